@@ -0,0 +1,155 @@
+use crate::coord::Coordinate;
+use crate::grid::Grid;
+
+/// Outcome of [`Grid::evolve`], once the grid stops changing or runs out of generations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Evolution {
+    /// Number of generations that changed at least one cell.
+    pub generations: usize,
+    /// Total number of cell updates over all generations.
+    pub changes: usize,
+    /// Whether the grid reached a fixpoint, rather than stopping at the generation limit.
+    pub settled: bool,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Runs synchronous generations of a cellular automaton until a fixpoint is reached or
+    /// `max_generations` generations have changed the grid.
+    ///
+    /// `rule` receives a cell and its 8 surrounding cells, clockwise from up and `None` outside
+    /// the grid, and returns the cell's next state. Every cell of a generation sees the state of
+    /// the previous one. The first generation visits the whole grid; later ones only visit the
+    /// frontier of cells that changed and their neighbours, since nothing else can change.
+    ///
+    /// Rules that oscillate never reach a fixpoint and stop at the limit with
+    /// [`Evolution::settled`] unset.
+    pub fn evolve(
+        &mut self,
+        max_generations: usize,
+        rule: impl Fn(&T, [Option<&T>; 8]) -> T,
+    ) -> Evolution {
+        let mut evolution = Evolution::default();
+        let mut frontier: Vec<Coordinate<usize>> = self.coordinates().collect();
+        let mut queued = Grid::filled(false, self.num_rows, self.num_columns);
+        loop {
+            let updates: Vec<(Coordinate<usize>, T)> = frontier
+                .iter()
+                .filter_map(|&coord| {
+                    let cell = &self[coord];
+                    let neighbours = self
+                        .surrounding(coord)
+                        .map(|neighbour| neighbour.map(|(_, value)| value));
+                    let next = rule(cell, neighbours);
+                    (next != *cell).then_some((coord, next))
+                })
+                .collect();
+            if updates.is_empty() {
+                evolution.settled = true;
+                return evolution;
+            }
+            if evolution.generations == max_generations {
+                return evolution;
+            }
+            evolution.generations += 1;
+            evolution.changes += updates.len();
+
+            frontier.clear();
+            for &(coord, _) in &updates {
+                let neighbours = self.surrounding(coord).map(|n| n.map(|(c, _)| c));
+                for next in std::iter::once(coord).chain(neighbours.into_iter().flatten()) {
+                    if !queued[next] {
+                        queued[next] = true;
+                        frontier.push(next);
+                    }
+                }
+            }
+            for (coord, next) in updates {
+                self[coord] = next;
+            }
+            for &coord in &frontier {
+                queued[coord] = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Boundary;
+
+    #[test]
+    fn test_evolve_to_fixpoint() {
+        // a burning cell sets its orthogonal and diagonal neighbours on fire
+        let mut grid = Grid::construct("*....\n.....\n....#", &|c| c);
+        let evolution = grid.evolve(10, |&cell, neighbours| {
+            if cell == '.' && neighbours.contains(&Some(&'*')) {
+                '*'
+            } else {
+                cell
+            }
+        });
+        assert_eq!(grid.to_string(), "*****\n*****\n****#");
+        assert_eq!(
+            evolution,
+            Evolution {
+                generations: 4,
+                changes: 13,
+                settled: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_evolve_is_synchronous() {
+        // every cell takes the value of its left neighbour, which only works if all cells see
+        // the previous generation
+        let mut grid = Grid::construct("1000", &|c| c);
+        let evolution = grid.evolve(10, |&cell, neighbours| match neighbours[6] {
+            Some(&'1') => '1',
+            _ => cell,
+        });
+        assert_eq!(grid.to_string(), "1111");
+        assert_eq!(evolution.generations, 3);
+    }
+
+    #[test]
+    fn test_evolve_clamped_edges() {
+        // a lone cell dies, also in a corner where clamping would otherwise return the cell
+        // itself as its neighbour
+        let mut grid = Grid::construct("#..\n...", &|c| c).with_boundary(Boundary::Clamp);
+        let evolution = grid.evolve(10, |&cell, neighbours| {
+            if neighbours.contains(&Some(&'#')) {
+                cell
+            } else {
+                '.'
+            }
+        });
+        assert_eq!(grid.to_string(), "...\n...");
+        assert_eq!(evolution.generations, 1);
+    }
+
+    #[test]
+    fn test_evolve_stops_oscillating() {
+        // every cell flips each generation, so the grid never settles
+        let mut grid = Grid::construct("01\n10", &|c| c);
+        let evolution = grid.evolve(5, |&cell, _| if cell == '0' { '1' } else { '0' });
+        assert_eq!(grid.to_string(), "10\n01");
+        assert_eq!(
+            evolution,
+            Evolution {
+                generations: 5,
+                changes: 20,
+                settled: false
+            }
+        );
+
+        // a grid that reaches its fixpoint right at the limit still counts as settled
+        let mut grid = Grid::construct("1000", &|c| c);
+        let evolution = grid.evolve(3, |&cell, neighbours| match neighbours[6] {
+            Some(&'1') => '1',
+            _ => cell,
+        });
+        assert!(evolution.settled);
+    }
+}
@@ -1,5 +1,8 @@
+use std::num::TryFromIntError;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
+use crate::direction::Direction;
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub struct Coordinate<T> {
     pub x: T,
@@ -58,39 +61,164 @@ impl<T> Coordinate<T> {
     }
 }
 
-impl Coordinate<usize> {
+/// Primitive integers usable as coordinate components, with the checked arithmetic the
+/// neighbour and distance methods are built on.
+pub trait Integer: Copy + Ord {
+    /// Unsigned integer of the same width, holding distances between two values.
+    type Unsigned: Integer;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl Integer for $t {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    <$t>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+impl<T: Integer> Coordinate<T> {
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Moves `distance` steps in `direction`, where north is towards smaller `y`. Returns `None`
+    /// when that leaves the range of `T`.
+    #[must_use]
+    pub fn step(self, direction: Direction, distance: T) -> Option<Self> {
+        let shift = |value: T, delta: isize| match delta {
+            -1 => value.checked_sub(distance),
+            1 => value.checked_add(distance),
+            _ => Some(value),
+        };
+        let delta = direction.delta();
+        let (x, y) = (shift(self.x, delta.x), shift(self.y, delta.y));
+        Some(Self { x: x?, y: y? })
+    }
+
+    /// Neighbours up, right, down and left, `None` where they would overflow.
     #[must_use]
     pub fn adjacent(self) -> [Option<Self>; 4] {
-        let up = (self.y > 0).then(|| self - Self { x: 0, y: 1 });
-        let right = (self.x < usize::MAX).then(|| self + Self { x: 1, y: 0 });
-        let down = (self.y < usize::MAX).then(|| self + Self { x: 0, y: 1 });
-        let left = (self.x > 0).then(|| self - Self { x: 1, y: 0 });
-        [up, right, down, left]
+        Direction::CARDINAL_DIRECTIONS.map(|direction| self.step(direction, T::ONE))
     }
 
+    /// Neighbours clockwise from up, including the diagonals, `None` where they would overflow.
     #[must_use]
     pub fn surrounding(self) -> [Option<Self>; 8] {
-        let up = (self.y > 0).then(|| self - Self { x: 0, y: 1 });
-        let topright = (self.x < usize::MAX && self.y > 0)
-            .then(|| self + Self { x: 1, y: 0 } - Self { x: 0, y: 1 });
-        let right = (self.x < usize::MAX).then(|| self + Self { x: 1, y: 0 });
-        let bottomright =
-            (self.x < usize::MAX && self.y < usize::MAX).then(|| self + Self { x: 1, y: 1 });
-        let down = (self.y < usize::MAX).then(|| self + Self { x: 0, y: 1 });
-        let bottomleft = (self.x > 0 && self.y < usize::MAX)
-            .then(|| self - Self { x: 1, y: 0 } + Self { x: 0, y: 1 });
-        let left = (self.x > 0).then(|| self - Self { x: 1, y: 0 });
-        let topleft = (self.x > 0 && self.y > 0).then(|| self - Self { x: 1, y: 1 });
-        [
-            up,
-            topright,
-            right,
-            bottomright,
-            down,
-            bottomleft,
-            left,
-            topleft,
-        ]
+        Direction::ALL_DIRECTIONS.map(|direction| self.step(direction, T::ONE))
+    }
+
+    /// Absolute difference per axis, which always fits the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Coordinate<T::Unsigned> {
+        Coordinate {
+            x: self.x.abs_diff(other.x),
+            y: self.y.abs_diff(other.y),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the distance does not fit the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> T::Unsigned {
+        let diff = self.abs_diff(other);
+        diff.x
+            .checked_add(diff.y)
+            .expect("manhattan distance overflows")
+    }
+
+    #[must_use]
+    pub fn chebyshev_distance(self, other: Self) -> T::Unsigned {
+        let diff = self.abs_diff(other);
+        diff.x.max(diff.y)
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the squared distance does not fit the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn squared_euclidean(self, other: Self) -> T::Unsigned {
+        let diff = self.abs_diff(other);
+        diff.x
+            .checked_mul(diff.x)
+            .and_then(|x| diff.y.checked_mul(diff.y).and_then(|y| x.checked_add(y)))
+            .expect("squared euclidean distance overflows")
+    }
+}
+
+impl TryFrom<Coordinate<usize>> for Coordinate<isize> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Coordinate<usize>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: value.x.try_into()?,
+            y: value.y.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Coordinate<isize>> for Coordinate<usize> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Coordinate<isize>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: value.x.try_into()?,
+            y: value.y.try_into()?,
+        })
+    }
+}
+
+impl Coordinate<usize> {
+    /// Every coordinate within the rectangle between the inclusive corners `min` and `max`, in
+    /// row-major order. Empty if `max` lies left of or above `min`.
+    pub fn rectangle(min: Self, max: Self) -> impl Iterator<Item = Self> {
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Coordinate { x, y }))
     }
 }
 
@@ -132,7 +260,7 @@ mod tests {
 
     #[test]
     fn test_adjacent_at_zero() {
-        let coord = Coordinate::new(0, 0);
+        let coord = Coordinate::new(0usize, 0);
         let adj = coord.adjacent();
 
         assert_eq!(adj[0], None); // up
@@ -156,6 +284,57 @@ mod tests {
         assert_eq!(surr[7], Some(Coordinate::new(0, 0))); // topleft
     }
 
+    #[test]
+    fn test_signed_neighbours() {
+        let coord = Coordinate::new(0isize, -1);
+        assert_eq!(coord.adjacent()[0], Some(Coordinate::new(0, -2)));
+        assert_eq!(coord.adjacent()[3], Some(Coordinate::new(-1, -1)));
+        let corner = Coordinate::new(isize::MIN, isize::MAX);
+        let surr = corner.surrounding();
+        assert_eq!(surr[0], Some(Coordinate::new(isize::MIN, isize::MAX - 1)));
+        assert_eq!(
+            surr[1],
+            Some(Coordinate::new(isize::MIN + 1, isize::MAX - 1))
+        );
+        assert_eq!(surr[3..=7], [None; 5]);
+    }
+
+    #[test]
+    fn test_step() {
+        let coord = Coordinate::new(5usize, 2);
+        assert_eq!(
+            coord.step(Direction::SouthWest, 3),
+            Some(Coordinate::new(2, 5))
+        );
+        assert_eq!(coord.step(Direction::North, 3), None);
+        assert_eq!(
+            Coordinate::new(1u8, 1).checked_add(Coordinate::new(255, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Coordinate::new(-3isize, 4);
+        let b = Coordinate::new(2, -8);
+        assert_eq!(a.abs_diff(b), Coordinate::new(5usize, 12));
+        assert_eq!(a.manhattan_distance(b), 17);
+        assert_eq!(a.chebyshev_distance(b), 12);
+        assert_eq!(a.squared_euclidean(b), 169);
+        let far = Coordinate::new(i8::MIN, 0).manhattan_distance(Coordinate::new(i8::MAX, 0));
+        assert_eq!(far, 255u8);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let unsigned = Coordinate::new(3usize, 7);
+        let signed = Coordinate::<isize>::try_from(unsigned).unwrap();
+        assert_eq!(signed, Coordinate::new(3, 7));
+        assert_eq!(Coordinate::<usize>::try_from(signed), Ok(unsigned));
+        assert!(Coordinate::<usize>::try_from(Coordinate::new(-1isize, 0)).is_err());
+        assert!(Coordinate::<isize>::try_from(Coordinate::new(usize::MAX, 0)).is_err());
+    }
+
     #[test]
     fn test_ord() {
         let a = Coordinate::new(1, 2);
@@ -165,4 +344,23 @@ mod tests {
         assert!(a < b);
         assert!(b < c);
     }
+
+    #[test]
+    fn test_rectangle() {
+        let coords: Vec<_> =
+            Coordinate::rectangle(Coordinate::new(1, 4), Coordinate::new(2, 5)).collect();
+        assert_eq!(
+            coords,
+            [
+                Coordinate::new(1, 4),
+                Coordinate::new(2, 4),
+                Coordinate::new(1, 5),
+                Coordinate::new(2, 5)
+            ]
+        );
+        assert_eq!(
+            Coordinate::rectangle(Coordinate::new(2, 0), Coordinate::new(1, 3)).count(),
+            0
+        );
+    }
 }
@@ -0,0 +1,252 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::coord::Integer;
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Coordinate3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Ord> Ord for Coordinate3<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.x
+            .cmp(&other.x)
+            .then(self.y.cmp(&other.y))
+            .then(self.z.cmp(&other.z))
+    }
+}
+
+impl<T: Ord> PartialOrd for Coordinate3<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Coordinate3<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Coordinate3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Coordinate3<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: SubAssign> SubAssign for Coordinate3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> Coordinate3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Moves `value` by -1, 0 or 1, `None` on overflow.
+fn nudge<T: Integer>(value: T, delta: i8) -> Option<T> {
+    match delta {
+        -1 => value.checked_sub(T::ONE),
+        1 => value.checked_add(T::ONE),
+        _ => Some(value),
+    }
+}
+
+impl<T: Integer> Coordinate3<T> {
+    fn offset(self, [dx, dy, dz]: [i8; 3]) -> Option<Self> {
+        Some(Self {
+            x: nudge(self.x, dx)?,
+            y: nudge(self.y, dy)?,
+            z: nudge(self.z, dz)?,
+        })
+    }
+
+    /// The 6 face neighbours, in the order -x, +x, -y, +y, -z, +z, `None` where they would
+    /// overflow.
+    #[must_use]
+    pub fn adjacent(self) -> [Option<Self>; 6] {
+        [
+            [-1, 0, 0],
+            [1, 0, 0],
+            [0, -1, 0],
+            [0, 1, 0],
+            [0, 0, -1],
+            [0, 0, 1],
+        ]
+        .map(|delta| self.offset(delta))
+    }
+
+    /// The 26 neighbours sharing a face, edge or corner, ordered by their x, y and z offsets,
+    /// `None` where they would overflow.
+    #[must_use]
+    pub fn surrounding(self) -> [Option<Self>; 26] {
+        let mut neighbours = [None; 26];
+        let deltas = (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| [dx, dy, dz])))
+            .filter(|&delta| delta != [0, 0, 0]);
+        for (neighbour, delta) in neighbours.iter_mut().zip(deltas) {
+            *neighbour = self.offset(delta);
+        }
+        neighbours
+    }
+
+    /// Absolute difference per axis, which always fits the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Coordinate3<T::Unsigned> {
+        Coordinate3 {
+            x: self.x.abs_diff(other.x),
+            y: self.y.abs_diff(other.y),
+            z: self.z.abs_diff(other.z),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the distance does not fit the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> T::Unsigned {
+        let diff = self.abs_diff(other);
+        diff.x
+            .checked_add(diff.y)
+            .and_then(|xy| xy.checked_add(diff.z))
+            .expect("manhattan distance overflows")
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the squared distance does not fit the unsigned counterpart of `T`.
+    #[must_use]
+    pub fn squared_euclidean(self, other: Self) -> T::Unsigned {
+        let diff = self.abs_diff(other);
+        [diff.x, diff.y, diff.z]
+            .into_iter()
+            .try_fold(T::Unsigned::ZERO, |sum, d| {
+                sum.checked_add(d.checked_mul(d)?)
+            })
+            .expect("squared euclidean distance overflows")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCoordinate3Error {
+    pub text: String,
+}
+
+impl fmt::Display for ParseCoordinate3Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid coordinate {:?}, expected `x,y,z`", self.text)
+    }
+}
+
+impl Error for ParseCoordinate3Error {}
+
+/// Parses `x,y,z`, allowing whitespace around each component, e.g. `162,817,812`.
+impl<T: FromStr> FromStr for Coordinate3<T> {
+    type Err = ParseCoordinate3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseCoordinate3Error {
+            text: s.to_string(),
+        };
+        let mut components = s.split(',').map(|component| component.trim().parse());
+        let mut next = || {
+            components
+                .next()
+                .ok_or_else(invalid)?
+                .map_err(|_| invalid())
+        };
+        let coordinate = Self::new(next()?, next()?, next()?);
+        if components.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Coordinate3::new(1, 2, 3);
+        let b = Coordinate3::new(4, -5, 6);
+        assert_eq!(a + b, Coordinate3::new(5, -3, 9));
+        assert_eq!(a - b, Coordinate3::new(-3, 7, -3));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(Coordinate3::new(1, 9, 9) < Coordinate3::new(2, 0, 0));
+        assert!(Coordinate3::new(1, 2, 3) < Coordinate3::new(1, 2, 4));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Coordinate3::new(162isize, 817, 812);
+        let b = Coordinate3::new(425, 690, 689);
+        assert_eq!(a.manhattan_distance(b), 263 + 127 + 123);
+        assert_eq!(a.squared_euclidean(b), 263 * 263 + 127 * 127 + 123 * 123);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Coordinate3::new(0usize, 0, 0);
+        let adjacent: Vec<_> = origin.adjacent().into_iter().flatten().collect();
+        assert_eq!(
+            adjacent,
+            [
+                Coordinate3::new(1, 0, 0),
+                Coordinate3::new(0, 1, 0),
+                Coordinate3::new(0, 0, 1)
+            ]
+        );
+        assert_eq!(origin.surrounding().iter().flatten().count(), 7);
+
+        let surrounding = Coordinate3::new(0isize, 0, 0).surrounding();
+        assert_eq!(surrounding[0], Some(Coordinate3::new(-1, -1, -1)));
+        assert_eq!(surrounding[25], Some(Coordinate3::new(1, 1, 1)));
+        assert!(surrounding.iter().all(|neighbour| neighbour.is_some()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("162,817,812".parse(), Ok(Coordinate3::new(162, 817, 812)));
+        assert_eq!(" -1, 2 ,3".parse(), Ok(Coordinate3::new(-1, 2, 3)));
+        assert!("1,2".parse::<Coordinate3<i32>>().is_err());
+        assert!("1,2,3,4".parse::<Coordinate3<i32>>().is_err());
+        assert!("1,x,3".parse::<Coordinate3<i32>>().is_err());
+    }
+}
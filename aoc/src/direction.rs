@@ -1,4 +1,11 @@
-#[derive(Clone, Copy)]
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::coord::Coordinate;
+use crate::parse::UnexpectedChar;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     North,
     NorthEast,
@@ -11,6 +18,7 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Clockwise from north, so that index `i` lies `i` eighths of a turn from north.
     pub const ALL_DIRECTIONS: [Direction; 8] = [
         Direction::North,
         Direction::NorthEast,
@@ -35,4 +43,169 @@ impl Direction {
         Direction::SouthWest,
         Direction::NorthWest,
     ];
+
+    /// Eighths of a full turn clockwise from north.
+    fn eighths(self) -> usize {
+        match self {
+            Direction::North => 0,
+            Direction::NorthEast => 1,
+            Direction::East => 2,
+            Direction::SouthEast => 3,
+            Direction::South => 4,
+            Direction::SouthWest => 5,
+            Direction::West => 6,
+            Direction::NorthWest => 7,
+        }
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    #[must_use]
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL_DIRECTIONS[(self.eighths() + eighths) % 8]
+    }
+
+    /// Turns 90° counterclockwise, e.g. from north to west.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 90° clockwise, e.g. from north to east.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 45° counterclockwise, e.g. from north to north-west.
+    #[must_use]
+    pub fn turn_left_45(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turns 45° clockwise, e.g. from north to north-east.
+    #[must_use]
+    pub fn turn_right_45(self) -> Self {
+        self.rotate(1)
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Offset of a single step, with north towards smaller `y` as in a grid's rows.
+    #[must_use]
+    pub fn delta(self) -> Coordinate<isize> {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Coordinate::new(x, y)
+    }
+}
+
+/// Reads compass letters `NESW`, arrow keys `UDLR` and arrows `^>v<`, where up is north.
+impl TryFrom<char> for Direction {
+    type Error = UnexpectedChar;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'N' | 'U' | '^' => Ok(Direction::North),
+            'E' | 'R' | '>' => Ok(Direction::East),
+            'S' | 'D' | 'v' => Ok(Direction::South),
+            'W' | 'L' | '<' => Ok(Direction::West),
+            _ => Err(UnexpectedChar(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    pub text: String,
+}
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid direction {:?}, expected one of N, NE, E, SE, S, SW, W, NW, U, D, L, R, ^, >, v or <",
+            self.text
+        )
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Parses the single characters accepted by `TryFrom<char>`, plus `NE`, `SE`, `SW` and `NW`.
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c).ok(),
+            _ => match s {
+                "NE" => Some(Direction::NorthEast),
+                "SE" => Some(Direction::SouthEast),
+                "SW" => Some(Direction::SouthWest),
+                "NW" => Some(Direction::NorthWest),
+                _ => None,
+            },
+        }
+        .ok_or_else(|| ParseDirectionError {
+            text: s.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right_45(), Direction::NorthWest);
+        assert_eq!(Direction::North.turn_left_45(), Direction::NorthWest);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        for (eighths, direction) in Direction::ALL_DIRECTIONS.into_iter().enumerate() {
+            assert_eq!(direction.eighths(), eighths);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.turn_right_45().turn_right_45(),
+                direction.turn_right()
+            );
+        }
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(Direction::North.delta(), Coordinate::new(0, -1));
+        assert_eq!(Direction::SouthWest.delta(), Coordinate::new(-1, 1));
+        for direction in Direction::ALL_DIRECTIONS {
+            assert_eq!(
+                direction.delta() + direction.opposite().delta(),
+                Coordinate::new(0, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Direction::try_from('^'), Ok(Direction::North));
+        assert_eq!(Direction::try_from('R'), Ok(Direction::East));
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert_eq!(Direction::try_from('x'), Err(UnexpectedChar('x')));
+        assert_eq!("W".parse(), Ok(Direction::West));
+        assert_eq!("NE".parse(), Ok(Direction::NorthEast));
+        assert!("NN".parse::<Direction>().is_err());
+        assert!("".parse::<Direction>().is_err());
+    }
 }
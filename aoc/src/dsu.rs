@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+/// Which root becomes the parent when two sets are merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnionBy {
+    /// Attach the set with fewer elements below the larger one.
+    #[default]
+    Size,
+    /// Attach the shallower tree below the deeper one.
+    Rank,
+}
+
+/// Disjoint-set forest over the elements `0..n`, with path compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    rank: Vec<u32>,
+    union_by: UnionBy,
+    num_components: usize,
+}
+
+impl UnionFind {
+    /// Creates `n` singleton sets that are merged by size.
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self::with_union_by(n, UnionBy::Size)
+    }
+
+    #[must_use]
+    pub fn with_union_by(n: usize, union_by: UnionBy) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            rank: vec![0; n],
+            union_by,
+            num_components: n,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets, kept up to date by every successful [`UnionFind::union`].
+    #[must_use]
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    /// Returns the root of the set containing `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `idx` is not smaller than [`UnionFind::len`].
+    pub fn find(&mut self, idx: usize) -> usize {
+        // find root idx
+        let mut root_idx = idx;
+        while root_idx != self.parent[root_idx] {
+            root_idx = self.parent[root_idx];
+        }
+        // path compression
+        let mut i = idx;
+        while i != root_idx {
+            let parent_idx = self.parent[i];
+            self.parent[i] = root_idx;
+            i = parent_idx;
+        }
+        root_idx
+    }
+
+    /// Merges the sets containing `idx_a` and `idx_b`, returning `false` if they already were
+    /// the same set.
+    pub fn union(&mut self, idx_a: usize, idx_b: usize) -> bool {
+        let root_a = self.find(idx_a);
+        let root_b = self.find(idx_b);
+        if root_a == root_b {
+            // already in same set
+            return false;
+        }
+        let a_below_b = match self.union_by {
+            UnionBy::Size => self.size[root_a] < self.size[root_b],
+            UnionBy::Rank => self.rank[root_a] < self.rank[root_b],
+        };
+        let (child, root) = if a_below_b {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[child] == self.rank[root] {
+            self.rank[root] += 1;
+        }
+        self.num_components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, idx_a: usize, idx_b: usize) -> bool {
+        self.find(idx_a) == self.find(idx_b)
+    }
+
+    /// Number of elements in the set containing `idx`.
+    pub fn size_of(&mut self, idx: usize) -> usize {
+        let root_idx = self.find(idx);
+        self.size[root_idx]
+    }
+
+    /// Sizes of all sets, in order of their smallest element.
+    pub fn sizes(&mut self) -> Vec<usize> {
+        let mut sizes = Vec::with_capacity(self.num_components);
+        for i in 0..self.len() {
+            if self.find(i) == i {
+                sizes.push(self.size[i]);
+            }
+        }
+        sizes
+    }
+
+    /// Members of every set, each sorted, in order of their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut slots: HashMap<usize, usize> = HashMap::with_capacity(self.num_components);
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.num_components);
+        for i in 0..self.len() {
+            let root_idx = self.find(i);
+            let slot = *slots.entry(root_idx).or_insert_with(|| {
+                components.push(Vec::with_capacity(self.size[root_idx]));
+                components.len() - 1
+            });
+            components[slot].push(i);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_components() {
+        for union_by in [UnionBy::Size, UnionBy::Rank] {
+            let mut union_find = UnionFind::with_union_by(6, union_by);
+            assert_eq!(union_find.num_components(), 6);
+            assert!(union_find.union(0, 3));
+            assert!(union_find.union(4, 3));
+            assert!(!union_find.union(0, 4));
+            assert!(union_find.union(1, 5));
+            assert_eq!(union_find.num_components(), 3);
+            assert!(union_find.same_set(0, 4));
+            assert!(!union_find.same_set(0, 1));
+            assert_eq!(union_find.size_of(3), 3);
+            assert_eq!(union_find.sizes(), vec![3, 2, 1]);
+            assert_eq!(
+                union_find.components(),
+                vec![vec![0, 3, 4], vec![1, 5], vec![2]]
+            );
+        }
+    }
+
+    #[test]
+    fn test_single_component() {
+        let mut union_find = UnionFind::new(4);
+        for i in 1..4 {
+            union_find.union(i - 1, i);
+        }
+        assert_eq!(union_find.num_components(), 1);
+        assert_eq!(union_find.components(), vec![vec![0, 1, 2, 3]]);
+    }
+}
@@ -0,0 +1,480 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::BitXorAssign;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Fixed-width vector over GF(2), packed into 64-bit words so it can be any width.
+///
+/// Bits beyond `len` in the last word are always zero, so equal vectors compare and hash equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// All-zero vector of `len` bits.
+    #[must_use]
+    pub fn zeros(len: usize) -> BitVec {
+        BitVec {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Vector of `len` bits with exactly the bits at `ones` set.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any index is out of bounds.
+    #[must_use]
+    pub fn from_ones(len: usize, ones: impl IntoIterator<Item = usize>) -> BitVec {
+        let mut bits = BitVec::zeros(len);
+        for index in ones {
+            bits.set(index, true);
+        }
+        bits
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "bit {index} out of bounds for length {}",
+            self.len
+        );
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `index` is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.check(index);
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `index` is out of bounds.
+    pub fn toggle(&mut self, index: usize) {
+        self.check(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    /// Hamming weight.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    index * WORD_BITS + bit
+                })
+            })
+        })
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec {
+    /// Adds `rhs` over GF(2).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the lengths differ.
+    fn bitxor_assign(&mut self, rhs: &BitVec) {
+        assert_eq!(self.len, rhs.len, "bit vector length mismatch");
+        for (word, &other) in self.words.iter_mut().zip(&rhs.words) {
+            *word ^= other;
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let bits: Vec<bool> = iter.into_iter().collect();
+        BitVec::from_ones(
+            bits.len(),
+            bits.iter()
+                .enumerate()
+                .filter(|(_, bit)| **bit)
+                .map(|(i, _)| i),
+        )
+    }
+}
+
+/// Prints the bits in index order as `0`s and `1`s.
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for index in 0..self.len {
+            write!(f, "{}", u8::from(self.get(index)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Linear system `Ax = b` over GF(2), built one equation at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct System {
+    num_variables: usize,
+    equations: Vec<(BitVec, bool)>,
+}
+
+/// All solutions of a [`System`]: `particular` plus any sum of `nullspace` vectors.
+///
+/// `particular` has all free variables cleared, and `nullspace[i]` has only the free variable
+/// `free_columns[i]` set among them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionSpace {
+    pub particular: BitVec,
+    pub nullspace: Vec<BitVec>,
+    pub free_columns: Vec<usize>,
+}
+
+impl System {
+    #[must_use]
+    pub fn new(num_variables: usize) -> System {
+        System {
+            num_variables,
+            equations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Adds the equation `coefficients · x = target`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `coefficients` does not have one bit per variable.
+    pub fn push(&mut self, coefficients: BitVec, target: bool) {
+        assert_eq!(
+            coefficients.len(),
+            self.num_variables,
+            "expected one coefficient per variable"
+        );
+        self.equations.push((coefficients, target));
+    }
+
+    /// Gauss-Jordan elimination, or `None` if the equations are inconsistent.
+    #[must_use]
+    pub fn solve(&self) -> Option<SolutionSpace> {
+        let mut rows = self.equations.clone();
+        let mut pivot_columns = Vec::new();
+        for column in 0..self.num_variables {
+            let pivot_row = pivot_columns.len();
+            let Some(best) = (pivot_row..rows.len()).find(|&i| rows[i].0.get(column)) else {
+                continue;
+            };
+            rows.swap(pivot_row, best);
+            let (pivot, target) = rows[pivot_row].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != pivot_row && row.0.get(column) {
+                    row.0 ^= &pivot;
+                    row.1 ^= target;
+                }
+            }
+            pivot_columns.push(column);
+        }
+        // rows without a pivot read 0 = target
+        if rows[pivot_columns.len()..]
+            .iter()
+            .any(|&(_, target)| target)
+        {
+            return None;
+        }
+
+        let mut particular = BitVec::zeros(self.num_variables);
+        for (&(_, target), &column) in rows.iter().zip(&pivot_columns) {
+            particular.set(column, target);
+        }
+        let free_columns: Vec<usize> = (0..self.num_variables)
+            .filter(|column| !pivot_columns.contains(column))
+            .collect();
+        let nullspace = free_columns
+            .iter()
+            .map(|&free| {
+                let mut basis = BitVec::zeros(self.num_variables);
+                basis.set(free, true);
+                for ((row, _), &column) in rows.iter().zip(&pivot_columns) {
+                    if row.get(free) {
+                        basis.set(column, true);
+                    }
+                }
+                basis
+            })
+            .collect();
+        Some(SolutionSpace {
+            particular,
+            nullspace,
+            free_columns,
+        })
+    }
+
+    /// A solution with the fewest set bits, or `None` if there is no solution.
+    ///
+    /// Enumerates the solution space when it has no more free variables than pivots, and
+    /// otherwise meets in the middle between `0` and `b` over the values of `Ax`, of which there
+    /// are `2^rank`. Either way the time is exponential in at most `n/2` for `n` variables, and
+    /// there is no limit on `n` itself.
+    #[must_use]
+    pub fn min_weight_solution(&self) -> Option<BitVec> {
+        let space = self.solve()?;
+        if space.dimension() <= self.num_variables - space.dimension() {
+            Some(space.min_weight())
+        } else {
+            space.meet_in_the_middle()
+        }
+    }
+}
+
+/// Calls `f` with `sum` plus every subset sum of `vectors`, visiting the subsets in Gray code
+/// order so each call adds a single vector.
+fn for_each_subset(vectors: &[BitVec], sum: &mut BitVec, f: &mut impl FnMut(&BitVec)) {
+    match vectors.split_last() {
+        None => f(sum),
+        Some((last, rest)) => {
+            for_each_subset(rest, sum, f);
+            *sum ^= last;
+            for_each_subset(rest, sum, f);
+        }
+    }
+}
+
+impl SolutionSpace {
+    /// Number of free variables; there are `2^dimension` solutions.
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.nullspace.len()
+    }
+
+    /// Calls `f` with every solution, changing one nullspace vector between calls.
+    pub fn for_each(&self, mut f: impl FnMut(&BitVec)) {
+        for_each_subset(&self.nullspace, &mut self.particular.clone(), &mut f);
+    }
+
+    /// The solution with the fewest set bits, by enumerating all of them.
+    #[must_use]
+    pub fn min_weight(&self) -> BitVec {
+        let mut best = self.particular.clone();
+        self.for_each(|x| {
+            if x.count_ones() < best.count_ones() {
+                best = x.clone();
+            }
+        });
+        best
+    }
+
+    /// The solution with the fewest set bits, by a breadth-first search from both ends over the
+    /// pivot rows of the reduced system, where pressing variable `j` adds column `j` to the
+    /// current value of `Ax`. The searches stop as soon as they meet, which happens after as
+    /// many steps as the fewest set bits, and together visit at most `2^rank` values.
+    fn meet_in_the_middle(&self) -> Option<BitVec> {
+        let num_variables = self.particular.len();
+        let pivot_columns: Vec<usize> = (0..num_variables)
+            .filter(|column| !self.free_columns.contains(column))
+            .collect();
+        let restrict = |x: &BitVec| -> BitVec { pivot_columns.iter().map(|&c| x.get(c)).collect() };
+        // column j of the reduced system: a unit vector for pivots, and the pivot part of its
+        // nullspace vector for free variables
+        let mut columns = vec![BitVec::zeros(pivot_columns.len()); num_variables];
+        for (row, &column) in pivot_columns.iter().enumerate() {
+            columns[column].set(row, true);
+        }
+        for (basis, &free) in self.nullspace.iter().zip(&self.free_columns) {
+            columns[free] = restrict(basis);
+        }
+
+        let start = [
+            BitVec::zeros(pivot_columns.len()),
+            restrict(&self.particular),
+        ];
+        if start[0] == start[1] {
+            return Some(BitVec::zeros(num_variables));
+        }
+        // each side maps the values it reached to the variable and value it reached them from
+        let mut reached: [HashMap<BitVec, Option<(usize, BitVec)>>; 2] =
+            start.clone().map(|value| HashMap::from([(value, None)]));
+        let mut frontiers = start.map(|value| vec![value]);
+        let meeting = 'search: loop {
+            let side = usize::from(frontiers[1].len() < frontiers[0].len());
+            if frontiers[side].is_empty() {
+                return None;
+            }
+            let mut next = Vec::new();
+            for value in &frontiers[side] {
+                for (j, column) in columns.iter().enumerate() {
+                    let mut step = value.clone();
+                    step ^= column;
+                    if reached[side].contains_key(&step) {
+                        continue;
+                    }
+                    reached[side].insert(step.clone(), Some((j, value.clone())));
+                    if reached[1 - side].contains_key(&step) {
+                        break 'search step;
+                    }
+                    next.push(step);
+                }
+            }
+            frontiers[side] = next;
+        };
+
+        let mut solution = BitVec::zeros(num_variables);
+        for side in &reached {
+            let mut value = &meeting;
+            while let Some((j, previous)) = &side[value] {
+                solution.toggle(*j);
+                value = previous;
+            }
+        }
+        Some(solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_vec() {
+        let mut bits = BitVec::from_ones(130, [0, 64, 129]);
+        assert_eq!(bits.count_ones(), 3);
+        assert!(bits.get(64) && !bits.get(63));
+        bits.toggle(63);
+        bits.set(0, false);
+        assert_eq!(bits.ones().collect::<Vec<_>>(), [63, 64, 129]);
+        bits ^= &BitVec::from_ones(130, [63, 64, 129]);
+        assert!(bits.is_zero());
+
+        let parsed: BitVec = [false, true, true, false].into_iter().collect();
+        assert_eq!(parsed.to_string(), "0110");
+        assert_eq!(parsed, BitVec::from_ones(4, [1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_bit_vec_out_of_bounds() {
+        let _ = BitVec::zeros(64).get(64);
+    }
+
+    // the first machine of the day-10 example: lights [.##.] and buttons
+    // (3) (1,3) (2) (2,3) (0,2) (0,1)
+    fn example() -> System {
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ];
+        let mut system = System::new(buttons.len());
+        for (light, target) in [false, true, true, false].into_iter().enumerate() {
+            let coefficients = buttons.iter().map(|button| button.contains(&light));
+            system.push(coefficients.collect(), target);
+        }
+        system
+    }
+
+    #[test]
+    fn test_solve() {
+        let space = example().solve().unwrap();
+        assert_eq!(space.dimension(), 2);
+        let mut solutions = Vec::new();
+        space.for_each(|x| solutions.push(x.clone()));
+        assert_eq!(solutions.len(), 4);
+        for x in &solutions {
+            for (row, target) in &example().equations {
+                let mut product = x.clone();
+                product
+                    .words
+                    .iter_mut()
+                    .zip(&row.words)
+                    .for_each(|(a, b)| *a &= b);
+                assert_eq!(product.count_ones() % 2 == 1, *target);
+            }
+        }
+
+        let mut inconsistent = System::new(2);
+        inconsistent.push(BitVec::from_ones(2, [0, 1]), true);
+        inconsistent.push(BitVec::from_ones(2, [0, 1]), false);
+        assert_eq!(inconsistent.solve(), None);
+        assert_eq!(inconsistent.min_weight_solution(), None);
+    }
+
+    #[test]
+    fn test_min_weight_strategies_agree() {
+        let system = example();
+        let space = system.solve().unwrap();
+        let enumerated = space.min_weight();
+        let met = space.meet_in_the_middle().unwrap();
+        assert_eq!(enumerated.count_ones(), 2);
+        assert_eq!(met.count_ones(), 2);
+
+        // two independent equations over 20 variables leave 18 free, so this meets in the middle
+        let mut wide = System::new(20);
+        wide.push(BitVec::from_ones(20, [3, 7, 11, 19]), true);
+        wide.push(BitVec::from_ones(20, [7, 11]), false);
+        let solution = wide.min_weight_solution().unwrap();
+        assert_eq!(solution.count_ones(), 1);
+        assert!(solution.get(3) || solution.get(19));
+    }
+
+    #[test]
+    fn test_min_weight_many_variables() {
+        // 100 variables in 20 disjoint equations of 5, so 80 are free and the fewest set bits
+        // is one per equation with a true target
+        let mut system = System::new(100);
+        for i in 0..20 {
+            let coefficients = BitVec::from_ones(100, (0..5).map(|k| i + 20 * k));
+            system.push(coefficients, [0, 5, 11].contains(&i));
+        }
+        let solution = system.min_weight_solution().unwrap();
+        assert_eq!(solution.count_ones(), 3);
+        for (row, target) in &system.equations {
+            let dot = solution.ones().filter(|&j| row.get(j)).count();
+            assert_eq!(dot % 2 == 1, *target);
+        }
+    }
+}
@@ -1,75 +1,320 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut};
 
 use crate::coord::Coordinate;
 use crate::direction::Direction;
+pub use crate::parse::UnexpectedChar;
 
-#[derive(Debug)]
+/// What a [`Grid`] does with coordinates and steps beyond its rows and columns.
+///
+/// A grid never grows past its dimensions. Puzzles that keep adding cells further out are better
+/// served by [`SparseGrid`](crate::sparse_grid::SparseGrid), which stores any coordinate it is
+/// given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// Nothing exists outside the grid: lookups return `None` and steps off an edge fail.
+    #[default]
+    Bounded,
+    /// Coordinates outside the grid snap to the nearest edge cell and steps stop at the edge.
+    /// A clamped step never counts as a neighbour, so edge cells have the same neighbours as
+    /// under [`Boundary::Bounded`] rather than themselves.
+    Clamp,
+    /// Opposite edges are joined, as on a torus.
+    Wrap,
+}
+
+/// Dense, row-major grid backed by a contiguous `Vec<T>`.
+///
+/// Cell `(x, y)` lives at index `y * num_columns + x`. Lookups and steps outside the grid follow
+/// its [`Boundary`]. For mostly-empty puzzles see [`SparseGrid`](crate::sparse_grid::SparseGrid).
+///
+/// Equality and hashing only look at the dimensions and cells, not at the boundary policy.
+#[derive(Debug, Clone)]
 pub struct Grid<T> {
-    pub map: HashMap<Coordinate<usize>, T>,
+    cells: Vec<T>,
     pub num_rows: usize,
     pub num_columns: usize,
+    boundary: Boundary,
 }
 
-impl<T> Grid<T>
-where
-    T: Clone,
-{
+impl<T> Grid<T> {
+    /// # Panics
+    ///
+    /// Will panic if `cells` does not hold exactly `num_rows * num_columns` values.
     #[must_use]
-    pub fn new(map: HashMap<Coordinate<usize>, T>, num_rows: usize, num_columns: usize) -> Grid<T> {
+    pub fn new(cells: Vec<T>, num_rows: usize, num_columns: usize) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            num_rows * num_columns,
+            "grid of {num_rows}x{num_columns} needs {} cells",
+            num_rows * num_columns
+        );
         Grid {
-            map,
+            cells,
             num_rows,
             num_columns,
+            boundary: Boundary::Bounded,
         }
     }
 
-    pub fn construct(input: &str, map_fn: &dyn Fn(char) -> T) -> Grid<T> {
+    #[must_use]
+    pub fn filled(value: T, num_rows: usize, num_columns: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(vec![value; num_rows * num_columns], num_rows, num_columns)
+    }
+
+    /// Parses one cell per character, treating every line of `input` as a row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` has no cells, if a row is longer or shorter than the first
+    /// row, or if `map_fn` rejects a character. A blank first line followed by other rows is a
+    /// ragged row 0, expected to be as long as the first non-blank line.
+    pub fn try_parse<E>(
+        input: &str,
+        map_fn: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseGridError<E>> {
         let lines = input.lines();
         let num_rows = lines.clone().count();
-        let num_columns = lines.clone().next().map_or(0, str::len);
+        let num_columns = lines.clone().next().map_or(0, |line| line.chars().count());
+        if num_columns == 0 {
+            // a blank first line only means empty input if every other line is blank too
+            return match lines.map(|line| line.chars().count()).find(|&len| len > 0) {
+                Some(expected) => Err(ParseGridError::RaggedRow {
+                    row: 0,
+                    column: 0,
+                    expected,
+                }),
+                None => Err(ParseGridError::Empty),
+            };
+        }
 
-        let map = lines
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(move |(x, ch)| (Coordinate { x, y }, map_fn(ch)))
-            })
-            .collect::<HashMap<Coordinate<usize>, T>>();
+        let mut cells = Vec::with_capacity(num_rows * num_columns);
+        for (row, line) in lines.enumerate() {
+            let mut found = 0;
+            for (column, ch) in line.chars().enumerate() {
+                if column == num_columns {
+                    return Err(ParseGridError::RaggedRow {
+                        row,
+                        column,
+                        expected: num_columns,
+                    });
+                }
+                let cell = map_fn(ch).map_err(|source| ParseGridError::InvalidCell {
+                    row,
+                    column,
+                    source,
+                })?;
+                cells.push(cell);
+                found += 1;
+            }
+            if found != num_columns {
+                return Err(ParseGridError::RaggedRow {
+                    row,
+                    column: found,
+                    expected: num_columns,
+                });
+            }
+        }
 
-        Grid {
-            map,
-            num_rows,
-            num_columns,
+        Ok(Grid::new(cells, num_rows, num_columns))
+    }
+
+    /// Infallible variant of [`Grid::try_parse`]; empty input gives an empty grid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the rows of `input` are not all the same length. This used to be accepted
+    /// silently, with the width taken from the first row; call [`Grid::try_parse`] to handle
+    /// ragged input instead.
+    pub fn construct(input: &str, map_fn: &dyn Fn(char) -> T) -> Grid<T> {
+        match Grid::try_parse(input, |ch| Ok::<T, Infallible>(map_fn(ch))) {
+            Ok(grid) => grid,
+            Err(ParseGridError::Empty) => Grid::new(Vec::new(), 0, 0),
+            Err(err) => panic!("{err}"),
         }
     }
 
+    /// Replaces the default [`Boundary::Bounded`] policy.
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: Boundary) -> Grid<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Whether `coord` is one of the stored cells, whatever the boundary.
+    #[must_use]
+    pub fn contains(&self, coord: &Coordinate<usize>) -> bool {
+        coord.x < self.num_columns && coord.y < self.num_rows
+    }
+
+    /// Maps `coord` onto the stored cell it refers to under the boundary policy.
+    fn resolve(&self, coord: &Coordinate<usize>) -> Option<Coordinate<usize>> {
+        if self.contains(coord) {
+            return Some(*coord);
+        }
+        if self.cells.is_empty() {
+            return None;
+        }
+        match self.boundary {
+            Boundary::Bounded => None,
+            Boundary::Clamp => Some(Coordinate {
+                x: coord.x.min(self.num_columns - 1),
+                y: coord.y.min(self.num_rows - 1),
+            }),
+            Boundary::Wrap => Some(Coordinate {
+                x: coord.x % self.num_columns,
+                y: coord.y % self.num_rows,
+            }),
+        }
+    }
+
+    fn index_of(&self, coord: &Coordinate<usize>) -> Option<usize> {
+        self.resolve(coord)
+            .map(|coord| coord.y * self.num_columns + coord.x)
+    }
+
+    fn coordinate_of(&self, index: usize) -> Coordinate<usize> {
+        Coordinate {
+            x: index % self.num_columns,
+            y: index / self.num_columns,
+        }
+    }
+
+    /// Sets the cell at `coord`, or the one it refers to under a clamping or wrapping boundary.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `coord` lies outside a [`Boundary::Bounded`] grid.
     pub fn set(&mut self, coord: Coordinate<usize>, val: T) {
-        self.map.insert(coord, val);
+        self[coord] = val;
+    }
+
+    #[must_use]
+    pub fn get(&self, coord: &Coordinate<usize>) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.get_ref(coord).cloned()
+    }
+
+    #[must_use]
+    pub fn get_ref(&self, coord: &Coordinate<usize>) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, coord: &Coordinate<usize>) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
     }
 
+    /// Returns the cells of row `y` as a contiguous slice.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `y` is not a row of the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.num_rows, "row {y} out of bounds");
+        &self.cells[y * self.num_columns..(y + 1) * self.num_columns]
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `y` is not a row of the grid.
     #[must_use]
-    pub fn get(&self, coord: &Coordinate<usize>) -> Option<T> {
-        self.map.get(coord).cloned()
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.num_rows, "row {y} out of bounds");
+        &mut self.cells[y * self.num_columns..(y + 1) * self.num_columns]
+    }
+
+    /// Returns the cells of column `x`, top to bottom.
+    ///
+    /// Columns are strided in row-major storage, so this is an iterator rather than a slice.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.num_columns, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.num_columns)
+    }
+
+    /// Iterates over all cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate<usize>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.coordinate_of(index), value))
+    }
+
+    /// Iterates over all coordinates in row-major order, without borrowing the grid.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate<usize>> + use<T> {
+        let max = Coordinate::new(self.num_columns, self.num_rows);
+        (max.x > 0 && max.y > 0)
+            .then(|| Coordinate::rectangle(Coordinate::new(0, 0), max - Coordinate::new(1, 1)))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Iterates over the coordinates of row `y`, left to right.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `y` is not a row of the grid.
+    pub fn row_coordinates(&self, y: usize) -> impl Iterator<Item = Coordinate<usize>> + use<T> {
+        assert!(y < self.num_rows, "row {y} out of bounds");
+        (0..self.num_columns).map(move |x| Coordinate { x, y })
+    }
+
+    /// Iterates over the coordinates of column `x`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `x` is not a column of the grid.
+    pub fn column_coordinates(&self, x: usize) -> impl Iterator<Item = Coordinate<usize>> + use<T> {
+        assert!(x < self.num_columns, "column {x} out of bounds");
+        (0..self.num_rows).map(move |y| Coordinate { x, y })
+    }
+
+    /// Iterates over the coordinates seen looking from `start` in `direction`, excluding `start`
+    /// itself, up to the edge of the grid.
+    ///
+    /// The ray always stops at the edge, whatever the boundary policy.
+    pub fn ray(
+        &self,
+        start: Coordinate<usize>,
+        direction: Direction,
+    ) -> impl Iterator<Item = Coordinate<usize>> + use<T> {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        std::iter::successors(Some(start), move |&position| {
+            step_within(position, direction, 1, num_rows, num_columns)
+        })
+        .skip(1)
     }
 
     pub fn search(&self, item: &T) -> Vec<Coordinate<usize>>
     where
         T: PartialEq,
     {
-        self.map
-            .iter()
-            .filter_map(
-                |(coord, value)| {
-                    if value == item { Some(*coord) } else { None }
-                },
-            )
+        self.iter()
+            .filter_map(|(coord, value)| (value == item).then_some(coord))
             .collect()
     }
 
-    /// # Panics
-    ///
-    /// Will panic if position type conversion fails.
+    /// Takes `step_size` steps from `position` in `direction`, following the boundary policy
+    /// when that crosses an edge.
     #[must_use]
     pub fn step(
         &self,
@@ -77,107 +322,716 @@ where
         direction: Direction,
         step_size: usize,
     ) -> Option<Coordinate<usize>> {
-        let step_i32 = i32::try_from(step_size).unwrap();
-        let x_i32 = i32::try_from(position.x).unwrap();
-        let y_i32 = i32::try_from(position.y).unwrap();
-
-        match direction {
-            Direction::North => (y_i32 - step_i32 >= 0).then(|| Coordinate {
-                x: position.x,
-                y: position.y - step_size,
-            }),
-            Direction::NorthEast => {
-                let north_bound = y_i32 - step_i32 >= 0;
-                let east_bound = position.x + step_size < self.num_columns;
-                (north_bound && east_bound).then(|| Coordinate {
-                    x: position.x + step_size,
-                    y: position.y - step_size,
-                })
-            }
-            Direction::East => (position.x + step_size < self.num_columns).then(|| Coordinate {
-                x: position.x + step_size,
-                y: position.y,
-            }),
-            Direction::SouthEast => {
-                let south_bound = position.y + step_size < self.num_rows;
-                let east_bound = position.x + step_size < self.num_columns;
-                (south_bound && east_bound).then(|| Coordinate {
-                    x: position.x + step_size,
-                    y: position.y + step_size,
-                })
-            }
-            Direction::South => (position.y + step_size < self.num_rows).then(|| Coordinate {
-                x: position.x,
-                y: position.y + step_size,
-            }),
-            Direction::SouthWest => {
-                let south_bound = position.y + step_size < self.num_rows;
-                let west_bound = x_i32 - step_i32 >= 0;
-                (south_bound && west_bound).then(|| Coordinate {
-                    x: position.x - step_size,
-                    y: position.y + step_size,
+        let delta = direction.delta();
+        match self.boundary {
+            Boundary::Bounded => step_within(
+                position,
+                direction,
+                step_size,
+                self.num_rows,
+                self.num_columns,
+            ),
+            Boundary::Clamp | Boundary::Wrap if self.cells.is_empty() => None,
+            Boundary::Clamp => {
+                let clamp = |value: usize, delta: isize, len: usize| {
+                    let value = match delta {
+                        -1 => value.saturating_sub(step_size),
+                        1 => value.saturating_add(step_size),
+                        _ => value,
+                    };
+                    value.min(len - 1)
+                };
+                Some(Coordinate {
+                    x: clamp(position.x, delta.x, self.num_columns),
+                    y: clamp(position.y, delta.y, self.num_rows),
                 })
             }
-            Direction::West => (x_i32 - step_i32 >= 0).then(|| Coordinate {
-                x: position.x - step_size,
-                y: position.y,
-            }),
-            Direction::NorthWest => {
-                let north_bound = y_i32 - step_i32 >= 0;
-                let west_bound = x_i32 - step_i32 >= 0;
-                (north_bound && west_bound).then(|| Coordinate {
-                    x: position.x - step_size,
-                    y: position.y - step_size,
+            Boundary::Wrap => {
+                let wrap = |value: usize, delta: isize, len: usize| {
+                    let (value, step_size) = (value % len, step_size % len);
+                    match delta {
+                        -1 => (value + len - step_size) % len,
+                        1 => (value + step_size) % len,
+                        _ => value,
+                    }
+                };
+                Some(Coordinate {
+                    x: wrap(position.x, delta.x, self.num_columns),
+                    y: wrap(position.y, delta.y, self.num_rows),
                 })
             }
         }
     }
 
+    /// The cell one step away in `direction`, where a step that [`Boundary::Clamp`] cuts short
+    /// has no neighbour instead of landing on `position` or on one of its other neighbours.
+    fn neighbour(
+        &self,
+        position: Coordinate<usize>,
+        direction: Direction,
+    ) -> Option<(Coordinate<usize>, &T)> {
+        let next = match self.boundary {
+            Boundary::Clamp => step_within(position, direction, 1, self.num_rows, self.num_columns),
+            _ => self.step(position, direction, 1),
+        }?;
+        self.get_ref(&next).map(|value| (next, value))
+    }
+
     #[must_use]
     pub fn adjacent(&self, position: Coordinate<usize>) -> [Option<(Coordinate<usize>, &T)>; 4] {
-        let north = self
-            .step(position, Direction::North, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let east = self
-            .step(position, Direction::East, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let south = self
-            .step(position, Direction::South, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let west = self
-            .step(position, Direction::West, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        [north, east, south, west]
+        Direction::CARDINAL_DIRECTIONS.map(|direction| self.neighbour(position, direction))
     }
 
     #[must_use]
     pub fn surrounding(&self, position: Coordinate<usize>) -> [Option<(Coordinate<usize>, &T)>; 8] {
-        let north = self
-            .step(position, Direction::North, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let north_east = self
-            .step(position, Direction::NorthEast, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let east = self
-            .step(position, Direction::East, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let south_east = self
-            .step(position, Direction::SouthEast, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let south = self
-            .step(position, Direction::South, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let south_west = self
-            .step(position, Direction::SouthWest, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let west = self
-            .step(position, Direction::West, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        let north_west = self
-            .step(position, Direction::NorthWest, 1)
-            .and_then(|c| self.map.get(&c).map(|v| (c, v)));
-        [
-            north, north_east, east, south_east, south, south_west, west, north_west,
-        ]
+        Direction::ALL_DIRECTIONS.map(|direction| self.neighbour(position, direction))
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_rows == other.num_rows
+            && self.num_columns == other.num_columns
+            && self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num_rows.hash(state);
+        self.num_columns.hash(state);
+        self.cells.hash(state);
+    }
+}
+
+impl<T> Index<Coordinate<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coordinate<usize>) -> &Self::Output {
+        self.get_ref(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Coordinate<usize>> for Grid<T> {
+    fn index_mut(&mut self, coord: Coordinate<usize>) -> &mut Self::Output {
+        self.get_mut(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} out of bounds"))
+    }
+}
+
+/// Transformations returning a new grid, with `num_rows` and `num_columns` swapped where the
+/// shape changes.
+impl<T: Clone> Grid<T> {
+    /// Builds a `num_rows` by `num_columns` grid whose cell at each coordinate is copied from
+    /// `source(coordinate)` in this grid.
+    fn remap(
+        &self,
+        num_rows: usize,
+        num_columns: usize,
+        source: impl Fn(Coordinate<usize>) -> Coordinate<usize>,
+    ) -> Grid<T> {
+        let cells = (0..num_rows)
+            .flat_map(|y| (0..num_columns).map(move |x| Coordinate { x, y }))
+            .map(|coord| self[source(coord)].clone())
+            .collect();
+        Grid::new(cells, num_rows, num_columns).with_boundary(self.boundary)
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    #[must_use]
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.num_columns, self.num_rows, |c| Coordinate {
+            x: c.y,
+            y: c.x,
+        })
+    }
+
+    /// Rotates the grid 90° clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let last_row = self.num_rows.saturating_sub(1);
+        self.remap(self.num_columns, self.num_rows, |c| Coordinate {
+            x: c.y,
+            y: last_row - c.x,
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (last_row, last_column) = (
+            self.num_rows.saturating_sub(1),
+            self.num_columns.saturating_sub(1),
+        );
+        self.remap(self.num_rows, self.num_columns, |c| Coordinate {
+            x: last_column - c.x,
+            y: last_row - c.y,
+        })
+    }
+
+    /// Rotates the grid 90° counterclockwise, i.e. 270° clockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let last_column = self.num_columns.saturating_sub(1);
+        self.remap(self.num_columns, self.num_rows, |c| Coordinate {
+            x: last_column - c.y,
+            y: c.x,
+        })
+    }
+
+    /// Mirrors the grid left to right, along its vertical axis.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let last_column = self.num_columns.saturating_sub(1);
+        self.remap(self.num_rows, self.num_columns, |c| Coordinate {
+            x: last_column - c.x,
+            y: c.y,
+        })
+    }
+
+    /// Mirrors the grid top to bottom, along its horizontal axis.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let last_row = self.num_rows.saturating_sub(1);
+        self.remap(self.num_rows, self.num_columns, |c| Coordinate {
+            x: c.x,
+            y: last_row - c.y,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrows the `num_rows` by `num_columns` rectangle with its top left corner at `origin`,
+    /// without copying any cells.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the rectangle does not fit inside the grid.
+    #[must_use]
+    pub fn view(
+        &self,
+        origin: Coordinate<usize>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> GridView<'_, T> {
+        assert!(
+            origin.y + num_rows <= self.num_rows && origin.x + num_columns <= self.num_columns,
+            "view of {num_rows}x{num_columns} at {origin:?} out of bounds"
+        );
+        GridView {
+            grid: self,
+            origin,
+            num_rows,
+            num_columns,
+        }
+    }
+}
+
+/// Rectangular window into a [`Grid`], created by [`Grid::view`]. Coordinates are relative to
+/// the top left corner of the window.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coordinate<usize>,
+    pub num_rows: usize,
+    pub num_columns: usize,
+}
+
+// not derived, as that would needlessly require `T: Clone`
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    #[must_use]
+    pub fn contains(&self, coord: &Coordinate<usize>) -> bool {
+        coord.x < self.num_columns && coord.y < self.num_rows
+    }
+
+    #[must_use]
+    pub fn get_ref(&self, coord: &Coordinate<usize>) -> Option<&'a T> {
+        if self.contains(coord) {
+            self.grid.get_ref(&(self.origin + *coord))
+        } else {
+            None
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `y` is not a row of the view.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.num_rows, "row {y} out of bounds");
+        &self.grid.row(self.origin.y + y)[self.origin.x..self.origin.x + self.num_columns]
+    }
+
+    /// Iterates over all cells of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate<usize>, &'a T)> {
+        let view = *self;
+        (0..self.num_rows).flat_map(move |y| {
+            view.row(y)
+                .iter()
+                .enumerate()
+                .map(move |(x, value)| (Coordinate { x, y }, value))
+        })
+    }
+
+    /// Copies the cells of the view into a grid of their own.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.num_rows)
+            .flat_map(|y| self.row(y).iter().cloned())
+            .collect();
+        Grid::new(cells, self.num_rows, self.num_columns)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid row by row, formatting each cell with `format_cell`.
+    ///
+    /// The returned [`Render`] implements [`Display`](fmt::Display) and can add coordinate
+    /// rulers and highlight cells, e.g. the ones visited by a search.
+    pub fn render_with<F, D>(&self, format_cell: F) -> Render<'_, T, F>
+    where
+        F: Fn(Coordinate<usize>, &T) -> D,
+        D: fmt::Display,
+    {
+        Render {
+            grid: self,
+            format_cell,
+            rulers: false,
+            highlighted: HashSet::new(),
+        }
+    }
+}
+
+/// Rows separated by newlines, the inverse of [`Grid::construct`] with the identity mapping.
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.render_with(|_, &cell| cell).fmt(f)
+    }
+}
+
+/// Grid rendering created by [`Grid::render_with`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    format_cell: F,
+    rulers: bool,
+    highlighted: HashSet<Coordinate<usize>>,
+}
+
+impl<T, F> Render<'_, T, F> {
+    /// Adds column numbers above the grid, written top to bottom, and row numbers to its left.
+    /// The column ruler assumes every cell renders as a single character.
+    #[must_use]
+    pub fn with_rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    /// Shows the given cells in reverse video, using ANSI escape codes.
+    #[must_use]
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Coordinate<usize>>) -> Self {
+        self.highlighted.extend(cells);
+        self
+    }
+}
+
+impl<T, F, D> fmt::Display for Render<'_, T, F>
+where
+    F: Fn(Coordinate<usize>, &T) -> D,
+    D: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let grid = self.grid;
+        let label_width = grid.num_rows.saturating_sub(1).to_string().len();
+        if self.rulers {
+            let digits = grid.num_columns.saturating_sub(1).to_string().len();
+            for digit in (0..digits).rev() {
+                write!(f, "{:label_width$} ", "")?;
+                for x in 0..grid.num_columns {
+                    let power = 10usize.pow(u32::try_from(digit).unwrap());
+                    // leave out leading zeros, except for column 0 itself
+                    if x < power && digit > 0 {
+                        write!(f, " ")?;
+                    } else {
+                        write!(f, "{}", x / power % 10)?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+        for y in 0..grid.num_rows {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{y:>label_width$} ")?;
+            }
+            for (x, cell) in grid.row(y).iter().enumerate() {
+                let coord = Coordinate { x, y };
+                let rendered = (self.format_cell)(coord, cell);
+                if self.highlighted.contains(&coord) {
+                    write!(f, "\x1b[7m{rendered}\x1b[0m")?;
+                } else {
+                    write!(f, "{rendered}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`Grid::try_parse`], positioned by zero-based row and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    /// The input contains no cells.
+    Empty,
+    /// A row ends at `column`, or runs past it, instead of having `expected` columns.
+    RaggedRow {
+        row: usize,
+        column: usize,
+        expected: usize,
+    },
+    /// The mapping function rejected the character at `row`, `column`.
+    InvalidCell {
+        row: usize,
+        column: usize,
+        source: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid input is empty"),
+            ParseGridError::RaggedRow {
+                row,
+                column,
+                expected,
+            } => write!(
+                f,
+                "row {row}, column {column}: expected rows of {expected} columns"
+            ),
+            ParseGridError::InvalidCell {
+                row,
+                column,
+                source,
+            } => write!(f, "row {row}, column {column}: {source}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseGridError::InvalidCell { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Takes `step_size` steps from `position` in `direction`, returning `None` when that leaves a
+/// grid of `num_rows` by `num_columns`.
+pub(crate) fn step_within(
+    position: Coordinate<usize>,
+    direction: Direction,
+    step_size: usize,
+    num_rows: usize,
+    num_columns: usize,
+) -> Option<Coordinate<usize>> {
+    position
+        .step(direction, step_size)
+        .filter(|next| next.x < num_columns && next.y < num_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn test_construct() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid.num_rows, 2);
+        assert_eq!(grid.num_columns, 3);
+        assert_eq!(grid.get(&Coordinate::new(2, 0)), Some('c'));
+        assert_eq!(grid.get(&Coordinate::new(0, 1)), Some('d'));
+        assert_eq!(grid.get(&Coordinate::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_try_parse_crlf_and_multibyte() {
+        let grid = Grid::try_parse("é.\r\n.é\r\n", Ok::<char, UnexpectedChar>).unwrap();
+        assert_eq!(grid.num_rows, 2);
+        assert_eq!(grid.num_columns, 2);
+        assert_eq!(grid.get(&Coordinate::new(1, 1)), Some('é'));
+    }
+
+    #[test]
+    fn test_try_parse_errors() {
+        let parse = |input| {
+            Grid::try_parse(input, |c| match c {
+                '.' | '#' => Ok(c),
+                _ => Err(UnexpectedChar(c)),
+            })
+        };
+        assert_eq!(parse("").unwrap_err(), ParseGridError::Empty);
+        assert_eq!(parse("\n\n").unwrap_err(), ParseGridError::Empty);
+        assert_eq!(
+            parse("\n.#.").unwrap_err(),
+            ParseGridError::RaggedRow {
+                row: 0,
+                column: 0,
+                expected: 3
+            }
+        );
+        assert_eq!(
+            parse("...\n..\n...").unwrap_err(),
+            ParseGridError::RaggedRow {
+                row: 1,
+                column: 2,
+                expected: 3
+            }
+        );
+        assert_eq!(
+            parse("...\n....").unwrap_err(),
+            ParseGridError::RaggedRow {
+                row: 1,
+                column: 3,
+                expected: 3
+            }
+        );
+        assert_eq!(
+            parse("..#\n.@.").unwrap_err(),
+            ParseGridError::InvalidCell {
+                row: 1,
+                column: 1,
+                source: UnexpectedChar('@')
+            }
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = Grid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid[Coordinate::new(1, 1)], 'e');
+        grid[Coordinate::new(1, 1)] = 'x';
+        assert_eq!(grid.get(&Coordinate::new(1, 1)), Some('x'));
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec!['c', 'f']);
+    }
+
+    #[test]
+    fn test_step_bounds() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        let origin = Coordinate::new(0, 0);
+        assert_eq!(grid.step(origin, Direction::North, 1), None);
+        assert_eq!(
+            grid.step(origin, Direction::SouthEast, 1),
+            Some(Coordinate::new(1, 1))
+        );
+        assert_eq!(grid.step(origin, Direction::East, 3), None);
+    }
+
+    #[test]
+    fn test_surrounding() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        let neighbours: Vec<char> = grid
+            .surrounding(Coordinate::new(0, 0))
+            .iter()
+            .filter_map(|sur| sur.map(|(_, c)| *c))
+            .collect();
+        assert_eq!(neighbours, vec!['b', 'e', 'd']);
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn test_render_with() {
+        let grid = Grid::construct("..#.\n#...", &|c| c == '#');
+        let render = grid.render_with(|_, &wall| if wall { '#' } else { '.' });
+        assert_eq!(render.to_string(), "..#.\n#...");
+        let render = grid
+            .render_with(|coord, &wall| {
+                if wall {
+                    '#'.to_string()
+                } else {
+                    coord.x.to_string()
+                }
+            })
+            .highlight([Coordinate::new(1, 1)]);
+        assert_eq!(render.to_string(), "01#3\n#\x1b[7m1\x1b[0m23");
+    }
+
+    #[test]
+    fn test_render_rulers() {
+        let grid = Grid::filled('.', 11, 12);
+        let rendered = grid.render_with(|_, &c| c).with_rulers().to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "             11");
+        assert_eq!(lines[1], "   012345678901");
+        assert_eq!(lines[2], " 0 ............");
+        assert_eq!(lines[12], "10 ............");
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        let rotated = grid.rotate_cw();
+        assert_eq!((rotated.num_rows, rotated.num_columns), (3, 2));
+        assert_eq!(rotated.to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_180().to_string(), "fed\ncba");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(rotated.rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+    }
+
+    #[test]
+    fn test_flip() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::construct("abcd\nefgh\nijkl", &|c| c);
+        let view = grid.view(Coordinate::new(1, 1), 2, 2);
+        assert_eq!(view.row(1), ['j', 'k']);
+        assert_eq!(view.get_ref(&Coordinate::new(1, 0)), Some(&'g'));
+        assert_eq!(view.get_ref(&Coordinate::new(2, 0)), None);
+        let cells: String = view.iter().map(|(_, &c)| c).collect();
+        assert_eq!(cells, "fgjk");
+        assert_eq!(view.to_grid().to_string(), "fg\njk");
+    }
+
+    #[test]
+    fn test_clamp_boundary() {
+        let grid = Grid::construct(EXAMPLE, &|c| c).with_boundary(Boundary::Clamp);
+        assert_eq!(grid.get(&Coordinate::new(7, 0)), Some('c'));
+        assert_eq!(
+            grid.step(Coordinate::new(1, 0), Direction::NorthWest, 3),
+            Some(Coordinate::new(0, 0))
+        );
+        assert_eq!(
+            grid.step(Coordinate::new(1, 0), Direction::East, 5),
+            Some(Coordinate::new(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_clamp_edge_neighbours() {
+        let bounded = Grid::construct(EXAMPLE, &|c| c);
+        let clamped = bounded.clone().with_boundary(Boundary::Clamp);
+        for coord in bounded.coordinates() {
+            assert_eq!(clamped.adjacent(coord), bounded.adjacent(coord));
+            assert_eq!(clamped.surrounding(coord), bounded.surrounding(coord));
+        }
+        let corner: String = clamped
+            .surrounding(Coordinate::new(0, 0))
+            .iter()
+            .flatten()
+            .map(|&(_, &c)| c)
+            .collect();
+        assert_eq!(corner, "bed");
+    }
+
+    #[test]
+    fn test_equality_ignores_boundary() {
+        use std::collections::HashSet;
+
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        let wrapping = grid.clone().with_boundary(Boundary::Wrap);
+        assert_eq!(grid, wrapping);
+        assert_eq!(HashSet::from([grid.clone(), wrapping]).len(), 1);
+        assert_ne!(grid, Grid::construct("abc\ndeg", &|c| c));
+        assert_ne!(grid, Grid::construct("ab\ncd\nef", &|c| c));
+    }
+
+    #[test]
+    fn test_wrap_boundary() {
+        let grid = Grid::construct(EXAMPLE, &|c| c).with_boundary(Boundary::Wrap);
+        assert_eq!(grid.get(&Coordinate::new(4, 3)), Some('e'));
+        assert_eq!(
+            grid.step(Coordinate::new(0, 0), Direction::NorthWest, 1),
+            Some(Coordinate::new(2, 1))
+        );
+        assert_eq!(
+            grid.step(Coordinate::new(2, 1), Direction::East, 7),
+            Some(Coordinate::new(0, 1))
+        );
+        let neighbours: String = grid
+            .adjacent(Coordinate::new(0, 0))
+            .iter()
+            .flatten()
+            .map(|&(_, &c)| c)
+            .collect();
+        assert_eq!(neighbours, "dbdc");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_bounded_set_outside_panics() {
+        let mut grid = Grid::construct(EXAMPLE, &|c| c);
+        grid.set(Coordinate::new(3, 0), 'x');
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let grid = Grid::construct(EXAMPLE, &|c| c);
+        let cells: String = grid.coordinates().map(|coord| grid[coord]).collect();
+        assert_eq!(cells, "abcdef");
+        assert_eq!(Grid::<char>::new(Vec::new(), 0, 0).coordinates().count(), 0);
+
+        let row: String = grid.row_coordinates(1).map(|coord| grid[coord]).collect();
+        assert_eq!(row, "def");
+        let column: String = grid
+            .column_coordinates(2)
+            .map(|coord| grid[coord])
+            .collect();
+        assert_eq!(column, "cf");
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::construct("abcd\nefgh\nijkl", &|c| c);
+        let ray = |start, direction| -> String {
+            grid.ray(start, direction)
+                .map(|coord| grid[coord])
+                .collect()
+        };
+        assert_eq!(ray(Coordinate::new(0, 0), Direction::SouthEast), "fk");
+        assert_eq!(ray(Coordinate::new(3, 2), Direction::North), "hd");
+        assert_eq!(ray(Coordinate::new(1, 1), Direction::West), "e");
+        assert_eq!(ray(Coordinate::new(3, 1), Direction::East), "");
+
+        let wrapping = grid.clone().with_boundary(Boundary::Wrap);
+        assert_eq!(
+            wrapping.ray(Coordinate::new(3, 1), Direction::East).count(),
+            0
+        );
     }
 }
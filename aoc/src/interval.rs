@@ -0,0 +1,340 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Integer types that can bound an inclusive interval.
+pub trait IntervalBound: Ord + Copy {
+    /// The next value, or `None` at the type's maximum.
+    fn successor(self) -> Option<Self>;
+    /// The previous value, or `None` at the type's minimum.
+    fn predecessor(self) -> Option<Self>;
+    /// Number of values in `start..=end`, assuming `start <= end`.
+    fn span_len(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_interval_bound {
+    ($($t:ty),*) => {
+        $(
+            impl IntervalBound for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[allow(clippy::cast_sign_loss)]
+                fn span_len(start: Self, end: Self) -> u128 {
+                    // widening through i128 keeps the difference exact for all 64-bit types
+                    (i128::from(end) - i128::from(start)) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_interval_bound!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl IntervalBound for usize {
+    fn successor(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn span_len(start: Self, end: Self) -> u128 {
+        (end - start) as u128 + 1
+    }
+}
+
+impl IntervalBound for isize {
+    fn successor(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn span_len(start: Self, end: Self) -> u128 {
+        end.abs_diff(start) as u128 + 1
+    }
+}
+
+/// Set of values stored as disjoint, non-adjacent inclusive spans.
+///
+/// Overlapping or touching ranges are merged on insert, so `1..=3` and `4..=6` become `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // span start -> span end, both inclusive
+    spans: BTreeMap<T, T>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            spans: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: IntervalBound> IntervalSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Number of disjoint spans in the set.
+    #[must_use]
+    pub fn num_spans(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Total number of values in the set.
+    #[must_use]
+    pub fn covered_len(&self) -> u128 {
+        self.spans
+            .iter()
+            .map(|(&start, &end)| T::span_len(start, end))
+            .sum()
+    }
+
+    /// Iterates over the disjoint spans in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.spans.iter().map(|(&start, &end)| start..=end)
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.spans
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, end)| end >= value)
+    }
+
+    /// Adds all values of `range`, merging it with any span it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // merge with the span starting at or before `start`
+        if let Some((&prev_start, &prev_end)) = self.spans.range(..=start).next_back()
+            && prev_end.successor().is_none_or(|after| after >= start)
+        {
+            start = prev_start;
+            end = end.max(prev_end);
+            self.spans.remove(&prev_start);
+        }
+        // merge with every span starting inside or right after the new one
+        loop {
+            let next = match end.successor() {
+                Some(after) => self.spans.range(start..=after).next(),
+                None => self.spans.range(start..).next(),
+            };
+            let Some((&next_start, &next_end)) = next else {
+                break;
+            };
+            end = end.max(next_end);
+            self.spans.remove(&next_start);
+        }
+        self.spans.insert(start, end);
+    }
+
+    /// Removes all values of `range`, splitting any span that only partly overlaps it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .spans
+            .range(..=start)
+            .next_back()
+            .map_or(start, |(&prev_start, _)| prev_start);
+        let overlapping: Vec<(T, T)> = self
+            .spans
+            .range(first..=end)
+            .filter(|&(_, span_end)| *span_end >= start)
+            .map(|(&span_start, &span_end)| (span_start, span_end))
+            .collect();
+        for (span_start, span_end) in overlapping {
+            self.spans.remove(&span_start);
+            if span_start < start
+                && let Some(before) = start.predecessor()
+            {
+                self.spans.insert(span_start, before);
+            }
+            if span_end > end
+                && let Some(after) = end.successor()
+            {
+                self.spans.insert(after, span_end);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let mut lhs = self.iter().peekable();
+        let mut rhs = other.iter().peekable();
+        while let (Some(a), Some(b)) = (lhs.peek(), rhs.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                result.spans.insert(start, end);
+            }
+            // drop whichever span ends first, the other may still overlap the next one
+            if a.end() < b.end() {
+                lhs.next();
+            } else {
+                rhs.next();
+            }
+        }
+        result
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: IntervalBound> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: IntervalBound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError {
+    pub range: String,
+}
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid range {:?}, expected `start-end`", self.range)
+    }
+}
+
+impl Error for ParseIntervalError {}
+
+/// Parses `start-end` ranges separated by commas and/or newlines, e.g. `11-22,95-115`.
+impl<T: IntervalBound + FromStr> FromStr for IntervalSet<T> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split([',', '\n'])
+            .map(str::trim)
+            .filter(|range| !range.is_empty())
+            .map(|range| {
+                let invalid = || ParseIntervalError {
+                    range: range.to_string(),
+                };
+                // skip the first character so a negative start is not taken as the separator
+                let split_at = range
+                    .get(1..)
+                    .and_then(|rest| rest.find('-'))
+                    .ok_or_else(invalid)?
+                    + 1;
+                let start = range[..split_at].parse().map_err(|_| invalid())?;
+                let end = range[split_at + 1..].parse().map_err(|_| invalid())?;
+                Ok(start..=end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18, 6..=6]
+            .into_iter()
+            .collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=6, 10..=20]);
+        assert_eq!(set.covered_len(), 15);
+        assert!(set.contains(&6));
+        assert!(!set.contains(&7));
+        assert!(set.contains(&20));
+        assert!(!set.contains(&21));
+    }
+
+    #[test]
+    fn test_covered_len_does_not_overflow() {
+        let set: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(set.covered_len(), u128::from(u64::MAX) + 1);
+        let set: IntervalSet<i64> = [i64::MIN..=-1, 0..=i64::MAX].into_iter().collect();
+        assert_eq!(set.num_spans(), 1);
+        assert_eq!(set.covered_len(), 1 << 64);
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        set.remove(5..=22);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=4, 23..=30]);
+        set.remove(i32::MIN..=0);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=4, 23..=30]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<u32> = [1..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet<u32> = [4..=11, 14..=20].into_iter().collect();
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![1..=20]);
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            vec![4..=5, 10..=11, 14..=15]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            vec![1..=3, 12..=13]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let set: IntervalSet<u64> = "11-22,95-115\n998-1012".parse().unwrap();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![11..=22, 95..=115, 998..=1012]
+        );
+        let set: IntervalSet<i32> = "-5--3,-1-2".parse().unwrap();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![-5..=-3, -1..=2]);
+        assert!("1-2,x".parse::<IntervalSet<u64>>().is_err());
+    }
+}
@@ -1,3 +1,13 @@
+pub mod automaton;
 pub mod coord;
+pub mod coord3;
 pub mod direction;
+pub mod dsu;
+pub mod gf2;
 pub mod grid;
+pub mod interval;
+pub mod linalg;
+pub mod parse;
+pub mod region;
+pub mod search;
+pub mod sparse_grid;
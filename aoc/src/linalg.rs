@@ -0,0 +1,591 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// Exact fraction `numerator / denominator`, always kept in lowest terms with a positive
+/// denominator, so equal values compare and hash equal.
+///
+/// Arithmetic panics on `i128` overflow rather than losing precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+/// Greatest common divisor, never negative; `gcd(0, 0)` is 0.
+#[must_use]
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// # Panics
+    ///
+    /// Will panic if `denominator` is zero.
+    #[must_use]
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert!(denominator != 0, "rational with zero denominator");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    #[must_use]
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    #[must_use]
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    #[must_use]
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value as an integer, or `None` if it has a fractional part.
+    #[must_use]
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `self` is zero.
+    #[must_use]
+    pub fn recip(self) -> Rational {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    fn checked(value: Option<i128>) -> i128 {
+        value.expect("rational arithmetic overflows i128")
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Rational {
+                fn from(value: $t) -> Self {
+                    Rational {
+                        numerator: i128::from(value),
+                        denominator: 1,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Rational {
+    fn from(value: usize) -> Self {
+        Rational::from(value as u64)
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(value: isize) -> Self {
+        Rational::from(value as i64)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        // scaling both sides to the least common denominator keeps intermediates small
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let (left, right) = (self.denominator / divisor, rhs.denominator / divisor);
+        let numerator = Rational::checked(
+            self.numerator
+                .checked_mul(right)
+                .zip(rhs.numerator.checked_mul(left))
+                .and_then(|(a, b)| a.checked_add(b)),
+        );
+        Rational::new(
+            numerator,
+            Rational::checked(self.denominator.checked_mul(right)),
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        // cancel crosswise first, so the products are already in lowest terms
+        let a = gcd(self.numerator, rhs.denominator).max(1);
+        let b = gcd(rhs.numerator, self.denominator).max(1);
+        Rational {
+            numerator: Rational::checked((self.numerator / a).checked_mul(rhs.numerator / b)),
+            denominator: Rational::checked((self.denominator / b).checked_mul(rhs.denominator / a)),
+        }
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is zero.
+    fn div(self, rhs: Rational) -> Rational {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Rational) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Rational) {
+        *self = *self - rhs;
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross-multiplying keeps the order
+        match (
+            self.numerator.checked_mul(other.denominator),
+            other.numerator.checked_mul(self.denominator),
+        ) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            _ => compare_fractions(
+                self.numerator,
+                self.denominator,
+                other.numerator,
+                other.denominator,
+            ),
+        }
+    }
+}
+
+/// Compares `a / b` with `c / d` for positive `b` and `d` without multiplying: first their
+/// integer parts, then the reciprocals of the remainders, as in Euclid's algorithm.
+fn compare_fractions(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let integer_parts = a.div_euclid(b).cmp(&c.div_euclid(d));
+    if integer_parts != Ordering::Equal {
+        return integer_parts;
+    }
+    match (a.rem_euclid(b), c.rem_euclid(d)) {
+        (0, 0) => Ordering::Equal,
+        (0, _) => Ordering::Less,
+        (_, 0) => Ordering::Greater,
+        // a / b < c / d exactly when b / a > d / c
+        (a, c) => compare_fractions(d, c, b, a),
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Dense, row-major matrix of [`Rational`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix {
+    entries: Vec<Rational>,
+    pub num_rows: usize,
+    pub num_columns: usize,
+}
+
+/// A matrix in reduced row echelon form, as returned by [`Matrix::rref`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rref {
+    pub matrix: Matrix,
+    /// Column of the leading one in each nonzero row, in row order. All later rows are zero.
+    pub pivot_columns: Vec<usize>,
+}
+
+/// Solution set of `Ax = b`, as returned by [`Matrix::solve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// The system is inconsistent.
+    None,
+    Unique(Vec<Rational>),
+    /// Every `particular + t_1 * nullspace[0] + ... + t_k * nullspace[k - 1]` is a solution.
+    ///
+    /// `particular` has all free variables set to zero, and `nullspace[i]` has a one for the
+    /// `i`-th free variable and zeros for the others, so `t_i` is the value of that variable.
+    Parametric {
+        particular: Vec<Rational>,
+        nullspace: Vec<Vec<Rational>>,
+        /// Column of the free variable of each nullspace vector.
+        free_columns: Vec<usize>,
+    },
+}
+
+impl Matrix {
+    /// # Panics
+    ///
+    /// Will panic if `entries` does not hold exactly `num_rows * num_columns` values.
+    #[must_use]
+    pub fn new(entries: Vec<Rational>, num_rows: usize, num_columns: usize) -> Matrix {
+        assert_eq!(
+            entries.len(),
+            num_rows * num_columns,
+            "expected {num_rows}x{num_columns} entries"
+        );
+        Matrix {
+            entries,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    #[must_use]
+    pub fn zero(num_rows: usize, num_columns: usize) -> Matrix {
+        Matrix::new(
+            vec![Rational::ZERO; num_rows * num_columns],
+            num_rows,
+            num_columns,
+        )
+    }
+
+    /// Builds a matrix from its rows, e.g. `Matrix::from_rows([[1, 2], [3, 4]])`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the rows differ in length.
+    pub fn from_rows<R, V>(rows: impl IntoIterator<Item = R>) -> Matrix
+    where
+        R: IntoIterator<Item = V>,
+        V: Into<Rational>,
+    {
+        let mut entries = Vec::new();
+        let mut num_rows = 0;
+        let mut num_columns = None;
+        for row in rows {
+            let len = entries.len();
+            entries.extend(row.into_iter().map(Into::into));
+            let row_len = entries.len() - len;
+            assert_eq!(
+                *num_columns.get_or_insert(row_len),
+                row_len,
+                "row {num_rows} differs in length"
+            );
+            num_rows += 1;
+        }
+        Matrix::new(entries, num_rows, num_columns.unwrap_or(0))
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if `y` is not a row of the matrix.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[Rational] {
+        assert!(y < self.num_rows, "row {y} out of bounds");
+        &self.entries[y * self.num_columns..(y + 1) * self.num_columns]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for column in 0..self.num_columns {
+            self.entries
+                .swap(a * self.num_columns + column, b * self.num_columns + column);
+        }
+    }
+
+    /// Computes `Ax`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `x` does not have one value per column.
+    #[must_use]
+    pub fn mul_vector(&self, x: &[Rational]) -> Vec<Rational> {
+        assert_eq!(x.len(), self.num_columns, "vector length mismatch");
+        (0..self.num_rows)
+            .map(|y| {
+                self.row(y)
+                    .iter()
+                    .zip(x)
+                    .fold(Rational::ZERO, |sum, (&a, &b)| sum + a * b)
+            })
+            .collect()
+    }
+
+    /// Gauss-Jordan elimination into reduced row echelon form.
+    #[must_use]
+    pub fn rref(&self) -> Rref {
+        let mut matrix = self.clone();
+        let mut pivot_columns = Vec::new();
+        for column in 0..self.num_columns {
+            let pivot_row = pivot_columns.len();
+            if pivot_row == self.num_rows {
+                break;
+            }
+            let Some(best) = (pivot_row..self.num_rows).find(|&y| !matrix[(y, column)].is_zero())
+            else {
+                continue;
+            };
+            matrix.swap_rows(pivot_row, best);
+            let scale = matrix[(pivot_row, column)].recip();
+            for x in column..self.num_columns {
+                matrix[(pivot_row, x)] = matrix[(pivot_row, x)] * scale;
+            }
+            for y in (0..self.num_rows).filter(|&y| y != pivot_row) {
+                let factor = matrix[(y, column)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for x in column..self.num_columns {
+                    let pivot_value = matrix[(pivot_row, x)];
+                    matrix[(y, x)] -= factor * pivot_value;
+                }
+            }
+            pivot_columns.push(column);
+        }
+        Rref {
+            matrix,
+            pivot_columns,
+        }
+    }
+
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rref().pivot_columns.len()
+    }
+
+    /// A basis of the solutions of `Ax = 0`, with one vector per free column as described for
+    /// [`Solution::Parametric`]. Empty if the columns are linearly independent.
+    #[must_use]
+    pub fn nullspace(&self) -> Vec<Vec<Rational>> {
+        self.rref().nullspace(self.num_columns)
+    }
+
+    /// Solves `Ax = b`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `b` does not have one value per row.
+    #[must_use]
+    pub fn solve(&self, b: &[Rational]) -> Solution {
+        assert_eq!(b.len(), self.num_rows, "right-hand side length mismatch");
+        let num_columns = self.num_columns + 1;
+        let augmented: Vec<Rational> = (0..self.num_rows)
+            .flat_map(|y| self.row(y).iter().copied().chain([b[y]]))
+            .collect();
+        let rref = Matrix::new(augmented, self.num_rows, num_columns).rref();
+        // a pivot in the last column means a row reading 0 = 1
+        if rref.pivot_columns.last() == Some(&self.num_columns) {
+            return Solution::None;
+        }
+        let mut particular = vec![Rational::ZERO; self.num_columns];
+        for (y, &column) in rref.pivot_columns.iter().enumerate() {
+            particular[column] = rref.matrix[(y, self.num_columns)];
+        }
+        let free_columns = rref.free_columns(self.num_columns);
+        if free_columns.is_empty() {
+            Solution::Unique(particular)
+        } else {
+            Solution::Parametric {
+                particular,
+                nullspace: rref.nullspace(self.num_columns),
+                free_columns,
+            }
+        }
+    }
+}
+
+impl Rref {
+    /// Columns without a pivot among the first `num_columns`.
+    #[must_use]
+    pub fn free_columns(&self, num_columns: usize) -> Vec<usize> {
+        (0..num_columns)
+            .filter(|column| !self.pivot_columns.contains(column))
+            .collect()
+    }
+
+    fn nullspace(&self, num_columns: usize) -> Vec<Vec<Rational>> {
+        self.free_columns(num_columns)
+            .into_iter()
+            .map(|free| {
+                let mut basis = vec![Rational::ZERO; num_columns];
+                basis[free] = Rational::ONE;
+                for (y, &column) in self.pivot_columns.iter().enumerate() {
+                    basis[column] = -self.matrix[(y, free)];
+                }
+                basis
+            })
+            .collect()
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Rational;
+
+    /// Indexes by `(row, column)`.
+    fn index(&self, (y, x): (usize, usize)) -> &Rational {
+        assert!(
+            y < self.num_rows && x < self.num_columns,
+            "({y}, {x}) out of bounds"
+        );
+        &self.entries[y * self.num_columns + x]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut Rational {
+        assert!(
+            y < self.num_rows && x < self.num_columns,
+            "({y}, {x}) out of bounds"
+        );
+        &mut self.entries[y * self.num_columns + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&value| Rational::from(value)).collect()
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(-2, -6);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(Rational::new(4, -2), Rational::from(-2));
+        assert_eq!(Rational::new(4, -2).to_integer(), Some(-2));
+        assert_eq!(half.to_integer(), None);
+        assert!(third < half && -half < Rational::ZERO);
+        assert_eq!(Rational::new(-3, 6).to_string(), "-1/2");
+        assert_eq!(Rational::from(7).to_string(), "7");
+    }
+
+    #[test]
+    fn test_rational_order_without_overflow() {
+        assert!(Rational::from(-i128::MAX) < Rational::from(i128::MAX));
+
+        // 1 + 1/2^100 against 1 + 1/(2^100 + 2), whose cross products overflow i128
+        let big = 1 << 100;
+        let above = Rational::new(big + 1, big);
+        let closer = Rational::new(big + 3, big + 2);
+        assert!(closer < above && Rational::ONE < closer);
+        assert!(-above < -closer);
+        assert_eq!(above.cmp(&Rational::new(big + 1, big)), Ordering::Equal);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn test_zero_denominator() {
+        let _ = Rational::ONE / Rational::ZERO;
+    }
+
+    #[test]
+    fn test_rref_and_rank() {
+        let matrix = Matrix::from_rows([[2, 4, 2], [1, 2, 3], [3, 6, 5]]);
+        let rref = matrix.rref();
+        assert_eq!(rref.pivot_columns, [0, 2]);
+        assert_eq!(
+            rref.matrix,
+            Matrix::from_rows([[1, 2, 0], [0, 0, 1], [0, 0, 0]])
+        );
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(Matrix::zero(2, 3).rank(), 0);
+    }
+
+    #[test]
+    fn test_nullspace() {
+        let matrix = Matrix::from_rows([[1, 1, 0, 1], [0, 1, 1, 2]]);
+        let nullspace = matrix.nullspace();
+        assert_eq!(
+            nullspace,
+            [rationals(&[1, -1, 1, 0]), rationals(&[1, -2, 0, 1])]
+        );
+        for basis in &nullspace {
+            assert_eq!(matrix.mul_vector(basis), rationals(&[0, 0]));
+        }
+        assert!(Matrix::from_rows([[1, 2], [3, 4]]).nullspace().is_empty());
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = Matrix::from_rows([[2, 1], [1, 3]]);
+        assert_eq!(
+            matrix.solve(&rationals(&[3, 5])),
+            Solution::Unique(vec![Rational::new(4, 5), Rational::new(7, 5)])
+        );
+
+        let singular = Matrix::from_rows([[1, 2], [2, 4]]);
+        assert_eq!(singular.solve(&rationals(&[1, 3])), Solution::None);
+        assert_eq!(
+            singular.solve(&rationals(&[1, 2])),
+            Solution::Parametric {
+                particular: rationals(&[1, 0]),
+                nullspace: vec![rationals(&[-2, 1])],
+                free_columns: vec![1],
+            }
+        );
+    }
+}
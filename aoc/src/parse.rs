@@ -0,0 +1,15 @@
+use std::error::Error;
+use std::fmt;
+
+/// Mapping error for inputs that only accept a fixed set of characters, such as grid cells or
+/// directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnexpectedChar(pub char);
+
+impl fmt::Display for UnexpectedChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected character {:?}", self.0)
+    }
+}
+
+impl Error for UnexpectedChar {}
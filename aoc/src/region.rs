@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+
+use crate::coord::Coordinate;
+use crate::grid::Grid;
+
+/// Which neighbours of a cell count as connected to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, right, down and left, as returned by [`Grid::adjacent`].
+    Four,
+    /// Including the diagonals, as returned by [`Grid::surrounding`].
+    Eight,
+}
+
+/// Smallest rectangle containing a region, with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Coordinate<usize>,
+    pub max: Coordinate<usize>,
+}
+
+impl BoundingBox {
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.max.x - self.min.x + 1
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.max.y - self.min.y + 1
+    }
+}
+
+/// Connected set of grid cells found by [`Grid::flood_fill`] or [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Cells in the order they were reached, starting from where the fill started.
+    pub cells: Vec<Coordinate<usize>>,
+    /// Number of cell sides bordering a cell outside the region or the edge of the grid.
+    ///
+    /// Sides are always counted 4-way, also for regions connected 8-way.
+    pub perimeter: usize,
+    pub bounding_box: BoundingBox,
+}
+
+impl Region {
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds the region of cells matching `predicate` that is connected to `start`, or `None`
+    /// if `start` is outside the grid or does not match itself.
+    pub fn flood_fill(
+        &self,
+        start: Coordinate<usize>,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Option<Region> {
+        let mut visited = Grid::filled(false, self.num_rows, self.num_columns);
+        self.fill_from(start, &predicate, connectivity, &mut visited)
+    }
+
+    /// Splits all cells matching `predicate` into connected regions, ordered by the first of
+    /// their cells in row-major order.
+    pub fn components(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Vec<Region> {
+        let mut visited = Grid::filled(false, self.num_rows, self.num_columns);
+        let mut regions = Vec::new();
+        for (coord, _) in self.iter() {
+            if !visited[coord]
+                && let Some(region) = self.fill_from(coord, &predicate, connectivity, &mut visited)
+            {
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    fn fill_from(
+        &self,
+        start: Coordinate<usize>,
+        predicate: &impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+        visited: &mut Grid<bool>,
+    ) -> Option<Region> {
+        if !self.contains(&start) || !self.get_ref(&start).is_some_and(predicate) {
+            return None;
+        }
+        let mut region = Region {
+            cells: Vec::new(),
+            perimeter: 0,
+            bounding_box: BoundingBox {
+                min: start,
+                max: start,
+            },
+        };
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(coord) = queue.pop_front() {
+            region.cells.push(coord);
+            let bounds = &mut region.bounding_box;
+            bounds.min = Coordinate::new(bounds.min.x.min(coord.x), bounds.min.y.min(coord.y));
+            bounds.max = Coordinate::new(bounds.max.x.max(coord.x), bounds.max.y.max(coord.y));
+            region.perimeter += self
+                .adjacent(coord)
+                .iter()
+                .filter(|neighbour| !neighbour.is_some_and(|(_, value)| predicate(value)))
+                .count();
+
+            let neighbours: Vec<_> = match connectivity {
+                Connectivity::Four => self.adjacent(coord).into_iter().flatten().collect(),
+                Connectivity::Eight => self.surrounding(coord).into_iter().flatten().collect(),
+            };
+            for (next, value) in neighbours {
+                if !visited[next] && predicate(value) {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        Some(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Boundary;
+
+    const ISLANDS: &str = "##..\n#..#\n..#.\n....";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::construct(ISLANDS, &|c| c);
+        let region = grid
+            .flood_fill(Coordinate::new(0, 0), |&c| c == '#', Connectivity::Four)
+            .unwrap();
+        assert_eq!(region.area(), 3);
+        assert_eq!(region.perimeter, 8);
+        assert_eq!(
+            region.bounding_box,
+            BoundingBox {
+                min: Coordinate::new(0, 0),
+                max: Coordinate::new(1, 1)
+            }
+        );
+        assert_eq!(
+            grid.flood_fill(Coordinate::new(2, 0), |&c| c == '#', Connectivity::Four),
+            None
+        );
+
+        // clamping at the edge must not make the corner region its own neighbour
+        let clamped = grid.clone().with_boundary(Boundary::Clamp);
+        let region = clamped
+            .flood_fill(Coordinate::new(0, 0), |&c| c == '#', Connectivity::Four)
+            .unwrap();
+        assert_eq!(region.perimeter, 8);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = Grid::construct(ISLANDS, &|c| c);
+        let areas = |connectivity| {
+            grid.components(|&c| c == '#', connectivity)
+                .iter()
+                .map(Region::area)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(areas(Connectivity::Four), [3, 1, 1]);
+        assert_eq!(areas(Connectivity::Eight), [3, 2]);
+
+        let water = grid.components(|&c| c == '.', Connectivity::Four);
+        assert_eq!(water.len(), 1);
+        assert_eq!(water[0].area(), 11);
+        assert_eq!(water[0].bounding_box.width(), 4);
+        assert_eq!(water[0].bounding_box.height(), 4);
+    }
+}
@@ -0,0 +1,388 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::coord::Coordinate;
+use crate::grid::Grid;
+
+/// Outcome of a successful search from a start state to a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N, C> {
+    /// Number of steps (BFS) or total cost (Dijkstra, A*) from start to goal.
+    pub distance: C,
+    /// States from start to goal, both included.
+    pub path: Vec<N>,
+    /// Number of states expanded before the goal was reached.
+    pub visited: usize,
+}
+
+/// Records every discovered state once, together with the state it was reached from.
+struct Explored<N> {
+    states: Vec<N>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N> Explored<N>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new(start: N) -> Self {
+        Self {
+            states: vec![start.clone()],
+            parents: vec![None],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Returns the index of `state`, and whether it was seen for the first time.
+    fn insert(&mut self, state: N, parent: usize) -> (usize, bool) {
+        if let Some(&index) = self.indices.get(&state) {
+            return (index, false);
+        }
+        let index = self.states.len();
+        self.indices.insert(state.clone(), index);
+        self.states.push(state);
+        self.parents.push(Some(parent));
+        (index, true)
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.states[index].clone()];
+        while let Some(parent) = self.parents[index] {
+            path.push(self.states[parent].clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search for the goal with the fewest steps from `start`.
+pub fn bfs<N, FN, IN, FS>(
+    start: N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<SearchResult<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut explored = Explored::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    let mut visited = 0;
+    while let Some((index, distance)) = queue.pop_front() {
+        visited += 1;
+        if success(&explored.states[index]) {
+            return Some(SearchResult {
+                distance,
+                path: explored.path_to(index),
+                visited,
+            });
+        }
+        for next in successors(&explored.states[index]) {
+            let (next_index, is_new) = explored.insert(next, index);
+            if is_new {
+                queue.push_back((next_index, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns every state reachable from `start`, including `start` itself.
+pub fn reachable<N, FN, IN>(start: N, mut successors: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Dijkstra's algorithm for the cheapest goal from `start`, given non-negative edge costs.
+pub fn dijkstra<N, C, FN, IN, FS>(
+    start: N,
+    successors: FN,
+    success: FS,
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// A* search for the cheapest goal from `start`.
+///
+/// `heuristic` must never overestimate the remaining cost, or the result may not be optimal.
+/// A state is expanded again whenever a cheaper route to it turns up, so the heuristic does not
+/// have to be consistent; with a consistent one, every state is expanded at most once.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut explored = Explored::new(start);
+    // best known cost per explored state, kept next to the states for lookup by index
+    let mut costs = vec![C::default()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&explored.states[0]), C::default(), 0))]);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            // stale heap entry, a cheaper route to this state was found since
+            continue;
+        }
+        visited += 1;
+        if success(&explored.states[index]) {
+            return Some(SearchResult {
+                distance: cost,
+                path: explored.path_to(index),
+                visited,
+            });
+        }
+        for (next, step_cost) in successors(&explored.states[index]) {
+            let next_cost = cost + step_cost;
+            let (next_index, is_new) = explored.insert(next, index);
+            if is_new {
+                costs.push(next_cost);
+            } else if next_cost >= costs[next_index] {
+                continue;
+            } else {
+                costs[next_index] = next_cost;
+                explored.parents[next_index] = Some(index);
+            }
+            let estimate = next_cost + heuristic(&explored.states[next_index]);
+            heap.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+    None
+}
+
+impl<T> Grid<T> {
+    /// Breadth-first distances from the nearest of `sources`, moving up, right, down and left
+    /// into cells that are `passable`.
+    ///
+    /// Sources are at distance 0 whether or not they are passable themselves; sources outside
+    /// the grid are ignored. Unreachable cells are `None`.
+    pub fn distance_map(
+        &self,
+        sources: impl IntoIterator<Item = Coordinate<usize>>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut distances = Grid::filled(None, self.num_rows, self.num_columns);
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut(&source) {
+                *distance = Some(0);
+                queue.push_back((source, 0));
+            }
+        }
+        while let Some((position, distance)) = queue.pop_front() {
+            for (next, value) in self.adjacent(position).into_iter().flatten() {
+                if distances[next].is_none() && passable(value) {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Counts the paths from `sources` to every cell, where `successors` gives the cells a path
+    /// can move to next.
+    ///
+    /// Paths must be monotone: every successor comes after its cell in row-major order, e.g.
+    /// one row further down. That allows counting in a single sweep over the grid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if a successor lies outside the grid or does not come after its cell.
+    pub fn count_paths<I>(
+        &self,
+        sources: impl IntoIterator<Item = Coordinate<usize>>,
+        successors: impl Fn(Coordinate<usize>, &T) -> I,
+    ) -> Grid<usize>
+    where
+        I: IntoIterator<Item = Coordinate<usize>>,
+    {
+        let mut counts = Grid::filled(0, self.num_rows, self.num_columns);
+        for source in sources {
+            counts[source] += 1;
+        }
+        for (position, value) in self.iter() {
+            let count = counts[position];
+            if count == 0 {
+                continue;
+            }
+            for next in successors(position, value) {
+                assert!(
+                    (next.y, next.x) > (position.y, position.x),
+                    "path from {position:?} to {next:?} is not monotone"
+                );
+                counts[next] += count;
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S.#.
+.##.
+...E";
+
+    fn open_neighbours(grid: &Grid<char>, position: Coordinate<usize>) -> Vec<Coordinate<usize>> {
+        grid.adjacent(position)
+            .into_iter()
+            .flatten()
+            .filter(|&(_, &cell)| cell != '#')
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid = Grid::construct(MAZE, &|c| c);
+        let start = grid.search(&'S')[0];
+        let end = grid.search(&'E')[0];
+        let result = bfs(start, |&p| open_neighbours(&grid, p), |&p| p == end).unwrap();
+        assert_eq!(result.distance, 5);
+        assert_eq!(result.path.len(), 6);
+        assert_eq!(result.path.first(), Some(&start));
+        assert_eq!(result.path.last(), Some(&end));
+    }
+
+    #[test]
+    fn test_bfs_unreachable() {
+        let grid = Grid::construct("S#E", &|c| c);
+        let end = Coordinate::new(2, 0);
+        let result = bfs(
+            Coordinate::new(0, 0),
+            |&p| open_neighbours(&grid, p),
+            |&p| p == end,
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let grid = Grid::construct(MAZE, &|c| c);
+        let seen = reachable(Coordinate::new(0, 0), |&p| open_neighbours(&grid, p));
+        assert_eq!(seen.len(), 9);
+    }
+
+    #[test]
+    fn test_dijkstra_on_strings() {
+        let edges: HashMap<String, Vec<(String, u32)>> = HashMap::from([
+            (
+                "you".to_string(),
+                vec![("aaa".to_string(), 1), ("bbb".to_string(), 5)],
+            ),
+            ("aaa".to_string(), vec![("ccc".to_string(), 1)]),
+            (
+                "ccc".to_string(),
+                vec![("bbb".to_string(), 1), ("out".to_string(), 9)],
+            ),
+            ("bbb".to_string(), vec![("out".to_string(), 2)]),
+        ]);
+        let result = dijkstra(
+            "you".to_string(),
+            |node| edges.get(node).cloned().unwrap_or_default(),
+            |node| node == "out",
+        )
+        .unwrap();
+        assert_eq!(result.distance, 5);
+        assert_eq!(result.path, vec!["you", "aaa", "ccc", "bbb", "out"]);
+    }
+
+    #[test]
+    fn test_astar_matches_bfs() {
+        let grid = Grid::construct(MAZE, &|c| c);
+        let start = grid.search(&'S')[0];
+        let end = grid.search(&'E')[0];
+        let result = astar(
+            start,
+            |&p| open_neighbours(&grid, p).into_iter().map(|c| (c, 1)),
+            |&p| p.x.abs_diff(end.x) + p.y.abs_diff(end.y),
+            |&p| p == end,
+        )
+        .unwrap();
+        assert_eq!(result.distance, 5);
+        assert!(result.visited <= 8);
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // the heuristic never overestimates, but drops by more than the cost from `a` to `b`,
+        // so `b` is first expanded through the direct and more expensive edge
+        let edges = HashMap::from([
+            ("s", vec![("a", 1), ("b", 3)]),
+            ("a", vec![("b", 1)]),
+            ("b", vec![("g", 3)]),
+        ]);
+        let result = astar(
+            "s",
+            |node| edges.get(node).cloned().unwrap_or_default(),
+            |&node| if node == "a" { 4 } else { 0 },
+            |&node| node == "g",
+        )
+        .unwrap();
+        assert_eq!(result.distance, 5);
+        assert_eq!(result.path, vec!["s", "a", "b", "g"]);
+    }
+
+    #[test]
+    fn test_distance_map() {
+        let grid = Grid::construct(MAZE, &|c| c);
+        let sources = [Coordinate::new(0, 0), Coordinate::new(3, 0)];
+        let distances = grid.distance_map(sources, |&cell| cell != '#');
+        let rendered = distances.render_with(|_, distance| match distance {
+            Some(distance) => distance.to_string(),
+            None => "#".to_string(),
+        });
+        assert_eq!(rendered.to_string(), "01#0\n1##1\n2332");
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // paths moving only down or down-right through open cells
+        let grid = Grid::construct("...\n.#.\n...", &|c| c);
+        let counts = grid.count_paths([Coordinate::new(0, 0)], |position, _| {
+            [Coordinate::new(0, 1), Coordinate::new(1, 1)]
+                .into_iter()
+                .map(move |delta| position + delta)
+                .filter(|next| grid.get(next) == Some('.'))
+        });
+        assert_eq!(counts.row(1), [1, 0, 0]);
+        assert_eq!(counts.row(2), [1, 1, 0]);
+    }
+}
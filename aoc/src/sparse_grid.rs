@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::coord::Coordinate;
+use crate::direction::Direction;
+use crate::grid::step_within;
+
+/// Map-backed grid that only stores the cells that were set.
+///
+/// Prefer [`Grid`](crate::grid::Grid) for puzzles where every cell is filled.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    pub map: HashMap<Coordinate<usize>, T>,
+    pub num_rows: usize,
+    pub num_columns: usize,
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone,
+{
+    #[must_use]
+    pub fn new(
+        map: HashMap<Coordinate<usize>, T>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> SparseGrid<T> {
+        SparseGrid {
+            map,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    pub fn construct(input: &str, map_fn: &dyn Fn(char) -> T) -> SparseGrid<T> {
+        let lines = input.lines();
+        let num_rows = lines.clone().count();
+        let num_columns = lines.clone().next().map_or(0, |line| line.chars().count());
+
+        let map = lines
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, ch)| (Coordinate { x, y }, map_fn(ch)))
+            })
+            .collect::<HashMap<Coordinate<usize>, T>>();
+
+        SparseGrid {
+            map,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    pub fn set(&mut self, coord: Coordinate<usize>, val: T) {
+        self.map.insert(coord, val);
+    }
+
+    #[must_use]
+    pub fn get(&self, coord: &Coordinate<usize>) -> Option<T> {
+        self.map.get(coord).cloned()
+    }
+
+    pub fn search(&self, item: &T) -> Vec<Coordinate<usize>>
+    where
+        T: PartialEq,
+    {
+        self.map
+            .iter()
+            .filter_map(|(coord, value)| (value == item).then_some(*coord))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn step(
+        &self,
+        position: Coordinate<usize>,
+        direction: Direction,
+        step_size: usize,
+    ) -> Option<Coordinate<usize>> {
+        step_within(
+            position,
+            direction,
+            step_size,
+            self.num_rows,
+            self.num_columns,
+        )
+    }
+
+    #[must_use]
+    pub fn adjacent(&self, position: Coordinate<usize>) -> [Option<(Coordinate<usize>, &T)>; 4] {
+        Direction::CARDINAL_DIRECTIONS.map(|direction| {
+            self.step(position, direction, 1)
+                .and_then(|c| self.map.get(&c).map(|v| (c, v)))
+        })
+    }
+
+    #[must_use]
+    pub fn surrounding(&self, position: Coordinate<usize>) -> [Option<(Coordinate<usize>, &T)>; 8] {
+        Direction::ALL_DIRECTIONS.map(|direction| {
+            self.step(position, direction, 1)
+                .and_then(|c| self.map.get(&c).map(|v| (c, v)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn test_construct() {
+        let grid = SparseGrid::construct(EXAMPLE, &|c| c);
+        assert_eq!(grid.num_rows, 2);
+        assert_eq!(grid.num_columns, 3);
+        assert_eq!(grid.map.len(), 6);
+        assert_eq!(grid.get(&Coordinate::new(2, 0)), Some('c'));
+        assert_eq!(grid.get(&Coordinate::new(0, 1)), Some('d'));
+        assert_eq!(grid.get(&Coordinate::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_set_and_search() {
+        let mut grid = SparseGrid::new(HashMap::new(), 100, 100);
+        assert_eq!(grid.get(&Coordinate::new(40, 60)), None);
+        grid.set(Coordinate::new(40, 60), '#');
+        grid.set(Coordinate::new(99, 0), '#');
+        grid.set(Coordinate::new(99, 0), '.');
+        assert_eq!(grid.get(&Coordinate::new(40, 60)), Some('#'));
+        assert_eq!(grid.search(&'#'), vec![Coordinate::new(40, 60)]);
+        assert_eq!(grid.map.len(), 2);
+    }
+
+    #[test]
+    fn test_step_bounds() {
+        let grid = SparseGrid::construct(EXAMPLE, &|c| c);
+        let corner = Coordinate::new(2, 1);
+        assert_eq!(grid.step(corner, Direction::East, 1), None);
+        assert_eq!(grid.step(corner, Direction::South, 1), None);
+        assert_eq!(
+            grid.step(corner, Direction::West, 2),
+            Some(Coordinate::new(0, 1))
+        );
+        assert_eq!(grid.step(corner, Direction::West, 3), None);
+        assert_eq!(
+            grid.step(corner, Direction::NorthWest, 1),
+            Some(Coordinate::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut grid = SparseGrid::new(HashMap::new(), 3, 3);
+        grid.set(Coordinate::new(1, 0), 'u');
+        grid.set(Coordinate::new(0, 1), 'l');
+        grid.set(Coordinate::new(2, 2), 'd');
+        let center = Coordinate::new(1, 1);
+        let adjacent: Vec<char> = grid
+            .adjacent(center)
+            .into_iter()
+            .flatten()
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(adjacent.len(), 2);
+        assert!(adjacent.contains(&'u') && adjacent.contains(&'l'));
+        assert_eq!(grid.surrounding(center).into_iter().flatten().count(), 3);
+
+        // unset cells and cells beyond the edge are both missing
+        let corner = grid.surrounding(Coordinate::new(0, 0));
+        assert_eq!(corner.into_iter().flatten().count(), 2);
+    }
+}
@@ -1,7 +1,5 @@
 // Advent of Code - Day 1: Secret Entrance
 
-const INPUT: &str = include_str!("./input.txt");
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Direction {
     Left,
@@ -9,7 +7,7 @@ enum Direction {
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Rotation {
+pub struct Rotation {
     direction: Direction,
     amount: u32,
 }
@@ -51,7 +49,7 @@ impl Dial {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Rotation> {
+pub fn parse_input(input: &str) -> Vec<Rotation> {
     input
         .lines()
         .filter_map(|line| {
@@ -67,8 +65,11 @@ fn parse_input(input: &str) -> Vec<Rotation> {
         .collect()
 }
 
-fn solve_part_one(input: &str) -> u32 {
-    let instructions = parse_input(input);
+pub fn solve_part_one(input: &str) -> u32 {
+    solve_part_one_parsed(&parse_input(input))
+}
+
+pub fn solve_part_one_parsed(instructions: &[Rotation]) -> u32 {
     let mut dial = Dial::new(50);
     instructions
         .iter()
@@ -79,8 +80,11 @@ fn solve_part_one(input: &str) -> u32 {
         .count().try_into().unwrap()
 }
 
-fn solve_part_two(input: &str) -> u32 {
-    let instructions = parse_input(input);
+pub fn solve_part_two(input: &str) -> u32 {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed(instructions: &[Rotation]) -> u32 {
     let mut dial = Dial::new(50);
 
     instructions
@@ -93,13 +97,6 @@ fn solve_part_two(input: &str) -> u32 {
         .sum()
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
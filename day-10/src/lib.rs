@@ -0,0 +1,886 @@
+// Advent of Code - Day 10: Factory
+
+use std::error::Error;
+use std::fmt;
+
+use aoc::gf2::{BitVec, System};
+use aoc::linalg::{Matrix, Rational, Solution, gcd};
+use rayon::prelude::*;
+
+#[derive(Debug)]
+pub struct Machine {
+    // 1-based line of the machine in the input
+    line: usize,
+    // diagram of required state of machine to start, where light i corresponds the i-th bit
+    // e.g. [.##.] -> 0110
+    diagram: BitVec,
+    // button represented by the lights it toggles, one bit per light
+    // e.g. (1,3) with four lights -> 0101
+    buttons: Vec<BitVec>,
+    // number of lights == number of counters
+    num_lights: usize,
+    // joltage target values
+    joltages: Vec<usize>,
+}
+
+impl Machine {
+    fn new(
+        line: usize,
+        diagram: BitVec,
+        buttons: Vec<BitVec>,
+        num_lights: usize,
+        joltages: Vec<usize>,
+    ) -> Self {
+        Self {
+            line,
+            diagram,
+            buttons,
+            num_lights,
+            joltages,
+        }
+    }
+
+    // Solves the system of linear equations: (Ax = t) over field F_2
+    // field F_2 has two element {0, 1}, where addition is XOR and multiplication is AND.
+    //
+    // Each indicator light state is the sum of presses of all the buttons that affect it,
+    // which can be simplified whether or not the button is pressed an odd or even number of times.
+    //
+    // Let N be the number of indicator lights, and M be the number of buttons.
+    // Let x_j be the parity of the number of times button j is pressed (0: odd, 1: even).
+    // let t_i be the target state for light i (0: off, 1: on)
+    // let A_ij be the matrix representing the wiring schematics, where
+    // A_ij = 1 if button j toggles light i, and A_ij = 0 otherwise.
+    //
+    // We need to find x1,x2...,xM such that for each light i:
+    // A_i1_x1 XOR A_i2_x2 XOR ... XOR A_iM_xM = t_i (Ax = t)
+    // This will give us all possible sets of parities x, and the fewest presses is the parity
+    // vector with the fewest ones, since pressing a button twice has no effect.
+    //
+    // Returns which buttons to press once each.
+    fn solve_part_one(&self) -> Option<BitVec> {
+        let mut system = System::new(self.buttons.len());
+        for i in 0..self.num_lights {
+            let coefficients = self.buttons.iter().map(|button| button.get(i)).collect();
+            system.push(coefficients, self.diagram.get(i));
+        }
+        system.min_weight_solution()
+    }
+
+    // Finds the fewest presses x >= 0 with Ax = t over the integers, where A_ij = 1 if button j
+    // increments counter i and t are the joltages, or None if no presses reach the joltages.
+    //
+    // Solving Ax = t over the rationals expresses every pivot variable in terms of the free
+    // variables, so only those are enumerated. Counters only ever increase, so a button is
+    // never pressed more often than the smallest joltage among the counters it is wired to,
+    // which bounds every variable.
+    //
+    // Returns how often to press each button.
+    fn solve_part_two(&self) -> Option<Vec<usize>> {
+        let num_buttons = self.buttons.len();
+        let bounds: Vec<i128> = self
+            .buttons
+            .iter()
+            .map(|button| {
+                button
+                    .ones()
+                    .map(|i| self.joltages[i] as i128)
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let wiring = Matrix::from_rows((0..self.num_lights).map(|i| {
+            self.buttons
+                .iter()
+                .map(move |button| u8::from(button.get(i)))
+        }));
+        let targets: Vec<Rational> = self.joltages.iter().map(|&j| Rational::from(j)).collect();
+        let (particular, nullspace, free_vars) = match wiring.solve(&targets) {
+            Solution::None => return None,
+            Solution::Unique(presses) => (presses, Vec::new(), Vec::new()),
+            Solution::Parametric {
+                particular,
+                nullspace,
+                free_columns,
+            } => (particular, nullspace, free_columns),
+        };
+
+        // x = particular + sum of x_f * nullspace_f over the free variables x_f, so every pivot
+        // variable and the total number of presses are affine in the free variables
+        let affine = |select: &dyn Fn(&[Rational]) -> Rational| {
+            Affine::new(
+                std::iter::once(select(&particular))
+                    .chain(nullspace.iter().map(|basis| select(basis)))
+                    .collect(),
+            )
+        };
+        let pivot_cols: Vec<usize> = (0..num_buttons)
+            .filter(|col| !free_vars.contains(col))
+            .collect();
+        let system = ReducedSystem {
+            pivots: pivot_cols
+                .iter()
+                .map(|&col| (affine(&|x| x[col]), bounds[col]))
+                .collect(),
+            presses: affine(&|x| x.iter().fold(Rational::ZERO, |sum, &x_j| sum + x_j)),
+        };
+        let free_ranges = free_vars.iter().map(|&var| (0, bounds[var])).collect();
+        let mut best = None;
+        system.search(0, free_ranges, &mut best);
+        let (_, free_values) = best?;
+
+        let mut presses = vec![0; num_buttons];
+        for (&var, &(value, _)) in free_vars.iter().zip(&free_values) {
+            presses[var] = value as usize;
+        }
+        for (&col, (pivot, _)) in pivot_cols.iter().zip(&system.pivots) {
+            presses[col] = (pivot.range(&free_values).0 / pivot.scale) as usize;
+        }
+        Some(presses)
+    }
+
+    // Toggles the lights of every pressed button, starting with all lights off.
+    fn replay_lights(&self, presses: &BitVec) -> BitVec {
+        let mut lights = BitVec::zeros(self.num_lights);
+        for j in presses.ones() {
+            lights ^= &self.buttons[j];
+        }
+        lights
+    }
+
+    // Increments the counters of every button as often as it is pressed, starting from zero.
+    fn replay_joltages(&self, presses: &[usize]) -> Vec<usize> {
+        let mut counters = vec![0; self.num_lights];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for i in button.ones() {
+                counters[i] += count;
+            }
+        }
+        counters
+    }
+
+    // Explains both parts for this machine: which buttons to press, and whether replaying
+    // those presses reproduces the diagram and joltages.
+    fn explain(&self) -> String {
+        let mut explanation = format!("line {}: {self}\n", self.line);
+        let check = |ok: bool| if ok { "ok" } else { "MISMATCH" };
+        let list = |pressed: Vec<String>, separator| {
+            if pressed.is_empty() {
+                "nothing".to_string()
+            } else {
+                pressed.join(separator)
+            }
+        };
+        match self.solve_part_one() {
+            Some(presses) => {
+                let lights = self.replay_lights(&presses);
+                let pressed: Vec<String> = presses
+                    .ones()
+                    .map(|j| format_button(&self.buttons[j]))
+                    .collect();
+                explanation += &format!(
+                    "  lights: press {}, {} presses, replays to {}: {}\n",
+                    list(pressed, " "),
+                    presses.count_ones(),
+                    format_diagram(&lights),
+                    check(lights == self.diagram)
+                );
+            }
+            None => explanation += "  lights: no presses reach the diagram\n",
+        }
+        match self.solve_part_two() {
+            Some(presses) => {
+                let joltages = self.replay_joltages(&presses);
+                let pressed: Vec<String> = self
+                    .buttons
+                    .iter()
+                    .zip(&presses)
+                    .filter(|&(_, &count)| count > 0)
+                    .map(|(button, count)| format!("{} {count}x", format_button(button)))
+                    .collect();
+                explanation += &format!(
+                    "  joltages: press {}, {} presses, replays to {}: {}\n",
+                    list(pressed, ", "),
+                    presses.iter().sum::<usize>(),
+                    format_joltages(&joltages),
+                    check(joltages == self.joltages)
+                );
+            }
+            None => explanation += "  joltages: no presses reach the joltages\n",
+        }
+        explanation
+    }
+}
+
+fn format_diagram(lights: &BitVec) -> String {
+    let lights: String = (0..lights.len())
+        .map(|i| if lights.get(i) { '#' } else { '.' })
+        .collect();
+    format!("[{lights}]")
+}
+
+fn format_button(button: &BitVec) -> String {
+    let lights: Vec<String> = button.ones().map(|i| i.to_string()).collect();
+    format!("({})", lights.join(","))
+}
+
+fn format_joltages(joltages: &[usize]) -> String {
+    let joltages: Vec<String> = joltages.iter().map(usize::to_string).collect();
+    format!("{{{}}}", joltages.join(","))
+}
+
+/// Writes the machine back in its input format, e.g. `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+impl fmt::Display for Machine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_diagram(&self.diagram))?;
+        for button in &self.buttons {
+            write!(f, " {}", format_button(button))?;
+        }
+        write!(f, " {}", format_joltages(&self.joltages))
+    }
+}
+
+// Affine function (constant + sum of coefficients[k] * x_k) / scale of the free variables x_k,
+// kept in integers so the search never needs fractions.
+struct Affine {
+    scale: i128,
+    constant: i128,
+    coefficients: Vec<i128>,
+}
+
+impl Affine {
+    // Takes the constant followed by the coefficients.
+    fn new(terms: Vec<Rational>) -> Self {
+        let scale = terms.iter().fold(1, |scale, term| {
+            scale / gcd(scale, term.denominator()) * term.denominator()
+        });
+        let mut terms = terms
+            .iter()
+            .map(|term| term.numerator() * (scale / term.denominator()));
+        Self {
+            scale,
+            constant: terms.next().unwrap_or(0),
+            coefficients: terms.collect(),
+        }
+    }
+
+    // Smallest and largest scaled value with every free variable within its range.
+    fn range(&self, free_ranges: &[(i128, i128)]) -> (i128, i128) {
+        let mut range = (self.constant, self.constant);
+        for (&coefficient, &(low, high)) in self.coefficients.iter().zip(free_ranges) {
+            let (a, b) = (coefficient * low, coefficient * high);
+            range.0 += a.min(b);
+            range.1 += a.max(b);
+        }
+        range
+    }
+
+    // Narrows the free variable ranges to values for which the scaled value can still lie in
+    // `min..=max`. Returns whether any range changed, or `None` if one became empty.
+    fn tighten(&self, min: i128, max: i128, free_ranges: &mut [(i128, i128)]) -> Option<bool> {
+        let (lowest, highest) = self.range(free_ranges);
+        if highest < min || lowest > max {
+            return None;
+        }
+        let mut changed = false;
+        for (&coefficient, range) in self.coefficients.iter().zip(free_ranges.iter_mut()) {
+            if coefficient == 0 || range.0 == range.1 {
+                continue;
+            }
+            // range of the value without this variable's term
+            let (a, b) = (coefficient * range.0, coefficient * range.1);
+            let (rest_min, rest_max) = (lowest - a.min(b), highest - a.max(b));
+            // min <= rest + coefficient * x <= max for some rest within its range
+            // (saturating, as the objective is only bounded on one side)
+            let mut from = min.saturating_sub(rest_max);
+            let mut to = max.saturating_sub(rest_min);
+            let mut coefficient = coefficient;
+            if coefficient < 0 {
+                (coefficient, from, to) =
+                    (-coefficient, to.saturating_neg(), from.saturating_neg());
+            }
+            let low = range.0.max(-from.saturating_neg().div_euclid(coefficient));
+            let high = range.1.min(to.div_euclid(coefficient));
+            if low > high {
+                return None;
+            }
+            changed |= (low, high) != *range;
+            *range = (low, high);
+        }
+        Some(changed)
+    }
+}
+
+// Reduced joltage system of a machine, see `Machine::solve_part_two`.
+struct ReducedSystem {
+    // each pivot variable with its bound
+    pivots: Vec<(Affine, i128)>,
+    presses: Affine,
+}
+
+impl ReducedSystem {
+    // Branches on the free variables from index `k` on, depth first, and back substitutes the
+    // pivot variables once all of them are fixed. Before branching, the ranges of the free
+    // variables are narrowed to what keeps every pivot variable within its bounds and the total
+    // below the best found so far; a branch is abandoned once any range becomes empty.
+    //
+    // `best` holds the fewest presses found so far, with the free variable values giving them.
+    fn search(
+        &self,
+        k: usize,
+        mut free_ranges: Vec<(i128, i128)>,
+        best: &mut Option<(i128, Vec<(i128, i128)>)>,
+    ) {
+        if !self.propagate(&mut free_ranges, best.as_ref().map(|&(min, _)| min)) {
+            return;
+        }
+        let Some(&(low, high)) = free_ranges.get(k) else {
+            for (pivot, _) in &self.pivots {
+                // the pivot variable also has to be an integer
+                if pivot.range(&free_ranges).0 % pivot.scale != 0 {
+                    return;
+                }
+            }
+            // all variables are integers, so their total is too
+            let presses = self.presses.range(&free_ranges).0 / self.presses.scale;
+            *best = Some((presses, free_ranges));
+            return;
+        };
+
+        // try the cheaper end of the range first to find a good bound early
+        let mut values: Box<dyn Iterator<Item = i128>> = Box::new(low..=high);
+        if self.presses.coefficients[k] < 0 {
+            values = Box::new((low..=high).rev());
+        }
+        for value in values {
+            let mut branch = free_ranges.clone();
+            branch[k] = (value, value);
+            self.search(k + 1, branch, best);
+        }
+    }
+
+    // Narrows the free variable ranges until nothing changes, returning `false` if no values
+    // are left.
+    fn propagate(&self, free_ranges: &mut [(i128, i128)], min_presses: Option<i128>) -> bool {
+        let max_presses = min_presses.map_or(i128::MAX, |min| (min - 1) * self.presses.scale);
+        loop {
+            let mut changed = match self.presses.tighten(i128::MIN, max_presses, free_ranges) {
+                Some(changed) => changed,
+                None => return false,
+            };
+            for (pivot, bound) in &self.pivots {
+                match pivot.tighten(0, pivot.scale * bound, free_ranges) {
+                    Some(pivot_changed) => changed |= pivot_changed,
+                    None => return false,
+                }
+            }
+            if !changed {
+                return true;
+            }
+        }
+    }
+}
+
+/// Machine whose indicator lights or joltage requirements no combination of button presses
+/// meets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfeasibleMachine {
+    /// 1-based line of the machine in the input.
+    pub line: usize,
+}
+
+impl fmt::Display for InfeasibleMachine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "no button presses configure the machine on line {}",
+            self.line
+        )
+    }
+}
+
+impl Error for InfeasibleMachine {}
+
+/// Why a machine line could not be parsed, with 1-based line and column numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMachineError {
+    /// The line does not start with an indicator light diagram such as `[.##.]`.
+    MissingDiagram { line: usize, column: usize },
+    /// The `[`, `(` or `{` at `column` is never closed.
+    Unclosed {
+        line: usize,
+        column: usize,
+        delimiter: char,
+    },
+    /// A diagram character other than `.` or `#`.
+    InvalidLight {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// A button wiring entry that is not a light index.
+    InvalidButton {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    /// A button wired to a light the diagram does not have.
+    LightOutOfRange {
+        line: usize,
+        column: usize,
+        light: usize,
+        num_lights: usize,
+    },
+    /// The line ends at `column` without joltage requirements such as `{3,5,4,7}`.
+    MissingJoltages { line: usize, column: usize },
+    InvalidJoltage {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    /// The joltage requirements starting at `column` do not have one value per light.
+    JoltageCount {
+        line: usize,
+        column: usize,
+        found: usize,
+        expected: usize,
+    },
+    /// Text that is neither a button wiring schematic nor part of the joltage requirements.
+    Unexpected {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl fmt::Display for ParseMachineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMachineError::MissingDiagram { line, column } => write!(
+                f,
+                "line {line}, column {column}: expected an indicator light diagram like `[.##.]`"
+            ),
+            ParseMachineError::Unclosed {
+                line,
+                column,
+                delimiter,
+            } => write!(f, "line {line}, column {column}: unclosed `{delimiter}`"),
+            ParseMachineError::InvalidLight {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {line}, column {column}: expected `.` or `#` in the light diagram, found {found:?}"
+            ),
+            ParseMachineError::InvalidButton { line, column, text } => write!(
+                f,
+                "line {line}, column {column}: invalid light index {text:?} in button wiring"
+            ),
+            ParseMachineError::LightOutOfRange {
+                line,
+                column,
+                light,
+                num_lights,
+            } => write!(
+                f,
+                "line {line}, column {column}: button wired to light {light}, but the machine has {num_lights} lights"
+            ),
+            ParseMachineError::MissingJoltages { line, column } => write!(
+                f,
+                "line {line}, column {column}: expected joltage requirements like `{{3,5,4,7}}`"
+            ),
+            ParseMachineError::InvalidJoltage { line, column, text } => {
+                write!(f, "line {line}, column {column}: invalid joltage {text:?}")
+            }
+            ParseMachineError::JoltageCount {
+                line,
+                column,
+                found,
+                expected,
+            } => write!(
+                f,
+                "line {line}, column {column}: expected {expected} joltage values, one per light, found {found}"
+            ),
+            ParseMachineError::Unexpected { line, column, text } => {
+                write!(f, "line {line}, column {column}: unexpected {text:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseMachineError {}
+
+/// Everything that can go wrong solving the day: malformed input or an unsolvable machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseMachineError),
+    Infeasible(InfeasibleMachine),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "{err}"),
+            SolveError::Infeasible(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(err) => Some(err),
+            SolveError::Infeasible(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseMachineError> for SolveError {
+    fn from(err: ParseMachineError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl From<InfeasibleMachine> for SolveError {
+    fn from(err: InfeasibleMachine) -> Self {
+        SolveError::Infeasible(err)
+    }
+}
+
+// Splits `text`, found at byte `offset` of its line, on commas into trimmed pieces with the
+// byte offset of each piece.
+fn split_list(text: &str, offset: usize) -> impl Iterator<Item = (usize, &str)> {
+    text.split(',').scan(offset, |start, piece| {
+        let trimmed = piece.trim_start();
+        let item = (*start + piece.len() - trimmed.len(), trimmed.trim_end());
+        *start += piece.len() + 1;
+        Some(item)
+    })
+}
+
+// Parses a machine such as `[.##.] (3) (1,3) (2) {3,5,4,7}` on 1-based line `line_number`.
+fn parse_machine(line_number: usize, line: &str) -> Result<Machine, ParseMachineError> {
+    // 1-based column of a byte offset into the line
+    let column = |offset: usize| line[..offset].chars().count() + 1;
+    let unexpected = |offset: usize| ParseMachineError::Unexpected {
+        line: line_number,
+        column: column(offset),
+        text: line[offset..]
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    };
+    // byte offset of the first non-whitespace character at or after `offset`
+    let skip_whitespace = |offset: usize| {
+        line[offset..]
+            .find(|c: char| !c.is_whitespace())
+            .map_or(line.len(), |skipped| offset + skipped)
+    };
+    // byte offset of the `close` delimiter matching the `open` one at `offset`
+    let closing = |offset: usize, open: char, close: char| {
+        line[offset..]
+            .find(close)
+            .map(|found| offset + found)
+            .ok_or(ParseMachineError::Unclosed {
+                line: line_number,
+                column: column(offset),
+                delimiter: open,
+            })
+    };
+
+    // parse indicator light diagram
+    let diagram_start = skip_whitespace(0);
+    if !line[diagram_start..].starts_with('[') {
+        return Err(ParseMachineError::MissingDiagram {
+            line: line_number,
+            column: column(diagram_start),
+        });
+    }
+    let diagram_end = closing(diagram_start, '[', ']')?;
+    let diagram_str = &line[diagram_start + 1..diagram_end];
+    if let Some((offset, found)) = diagram_str
+        .char_indices()
+        .find(|&(_, c)| c != '.' && c != '#')
+    {
+        return Err(ParseMachineError::InvalidLight {
+            line: line_number,
+            column: column(diagram_start + 1 + offset),
+            found,
+        });
+    }
+    let num_lights = diagram_str.len();
+    let diagram: BitVec = diagram_str.chars().map(|c| c == '#').collect();
+
+    // parse button wiring schematics, up to the joltage requirements
+    let mut buttons = Vec::new();
+    let mut position = skip_whitespace(diagram_end + 1);
+    while !line[position..].starts_with('{') {
+        if position == line.len() {
+            return Err(ParseMachineError::MissingJoltages {
+                line: line_number,
+                column: column(position),
+            });
+        }
+        if !line[position..].starts_with('(') {
+            return Err(unexpected(position));
+        }
+        let schematic_end = closing(position, '(', ')')?;
+        let indices_str = &line[position + 1..schematic_end];
+        let mut button = BitVec::zeros(num_lights);
+        if !indices_str.trim().is_empty() {
+            for (offset, s) in split_list(indices_str, position + 1) {
+                let light = s
+                    .parse::<usize>()
+                    .map_err(|_| ParseMachineError::InvalidButton {
+                        line: line_number,
+                        column: column(offset),
+                        text: s.to_string(),
+                    })?;
+                if light >= num_lights {
+                    return Err(ParseMachineError::LightOutOfRange {
+                        line: line_number,
+                        column: column(offset),
+                        light,
+                        num_lights,
+                    });
+                }
+                button.set(light, true);
+            }
+        }
+        buttons.push(button);
+        position = skip_whitespace(schematic_end + 1);
+    }
+
+    // parse joltage requirements
+    let joltage_end = closing(position, '{', '}')?;
+    let joltages = split_list(&line[position + 1..joltage_end], position + 1)
+        .map(|(offset, s)| {
+            s.parse::<usize>()
+                .map_err(|_| ParseMachineError::InvalidJoltage {
+                    line: line_number,
+                    column: column(offset),
+                    text: s.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if joltages.len() != num_lights {
+        return Err(ParseMachineError::JoltageCount {
+            line: line_number,
+            column: column(position),
+            found: joltages.len(),
+            expected: num_lights,
+        });
+    }
+    let trailing = skip_whitespace(joltage_end + 1);
+    if trailing < line.len() {
+        return Err(unexpected(trailing));
+    }
+    Ok(Machine::new(
+        line_number,
+        diagram,
+        buttons,
+        num_lights,
+        joltages,
+    ))
+}
+
+/// Parses one machine per non-blank line.
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseMachineError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_machine(index + 1, line))
+        .collect()
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, SolveError> {
+    Ok(solve_part_one_parsed(&parse_input(input)?)?)
+}
+
+pub fn solve_part_one_parsed(machines: &[Machine]) -> Result<usize, InfeasibleMachine> {
+    machines
+        .par_iter()
+        .map(|machine| {
+            machine
+                .solve_part_one()
+                .map(|presses| presses.count_ones())
+                .ok_or(InfeasibleMachine { line: machine.line })
+        })
+        .sum()
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, SolveError> {
+    Ok(solve_part_two_parsed(&parse_input(input)?)?)
+}
+
+pub fn solve_part_two_parsed(machines: &[Machine]) -> Result<usize, InfeasibleMachine> {
+    machines
+        .par_iter()
+        .map(|machine| {
+            machine
+                .solve_part_two()
+                .map(|presses| presses.iter().sum::<usize>())
+                .ok_or(InfeasibleMachine { line: machine.line })
+        })
+        .sum()
+}
+
+/// Lists the button presses of both parts for every machine, and checks that replaying them
+/// reproduces its indicator lights and joltages.
+pub fn explain(input: &str) -> Result<String, ParseMachineError> {
+    let machines = parse_input(input)?;
+    Ok(machines.iter().map(Machine::explain).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let answer = crate::solve_part_one(example_input).unwrap();
+        assert_eq!(answer, 7);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let answer = crate::solve_part_two(example_input).unwrap();
+        assert_eq!(answer, 33);
+    }
+
+    #[test]
+    fn part2_large_free_variable() {
+        // the cheapest plan presses the free button (0,1) 400 times
+        let example_input = "[..] (0) (1) (0,1) {500,400}";
+        let answer = crate::solve_part_two(example_input).unwrap();
+        assert_eq!(answer, 500);
+    }
+
+    #[test]
+    fn part2_infeasible() {
+        let example_input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[.#] (0) {1,2}";
+        let err = crate::solve_part_two(example_input).unwrap_err();
+        assert_eq!(
+            err,
+            crate::SolveError::Infeasible(crate::InfeasibleMachine { line: 2 })
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        use crate::ParseMachineError::*;
+
+        let error = |line: &str| crate::parse_input(line).unwrap_err();
+        assert_eq!(error("(0) {1}"), MissingDiagram { line: 1, column: 1 });
+        assert_eq!(
+            error("[.#. (0) {1}"),
+            Unclosed {
+                line: 1,
+                column: 1,
+                delimiter: '['
+            }
+        );
+        assert_eq!(
+            error("[.x] (0) {1,1}"),
+            InvalidLight {
+                line: 1,
+                column: 3,
+                found: 'x'
+            }
+        );
+        assert_eq!(
+            error("[.#] (0, a) {1,1}"),
+            InvalidButton {
+                line: 1,
+                column: 10,
+                text: "a".to_string()
+            }
+        );
+        assert_eq!(
+            error("[.#] (0,2) {1,1}"),
+            LightOutOfRange {
+                line: 1,
+                column: 9,
+                light: 2,
+                num_lights: 2
+            }
+        );
+        assert_eq!(
+            error("[.#] (0) (1"),
+            Unclosed {
+                line: 1,
+                column: 10,
+                delimiter: '('
+            }
+        );
+        assert_eq!(
+            error("[.#] (0)  "),
+            MissingJoltages {
+                line: 1,
+                column: 11
+            }
+        );
+        assert_eq!(
+            error("[.#] (0) {1,-1}"),
+            InvalidJoltage {
+                line: 1,
+                column: 13,
+                text: "-1".to_string()
+            }
+        );
+        assert_eq!(
+            error("[.#] (0) 1 {1,1}"),
+            Unexpected {
+                line: 1,
+                column: 10,
+                text: "1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error_positions() {
+        use crate::ParseMachineError::*;
+
+        // blank lines count, and columns include indentation
+        let input = "[.#] (0) (1) {1,1}\n\n  [.#] (0) {1,2,3}";
+        assert_eq!(
+            crate::parse_input(input).unwrap_err(),
+            JoltageCount {
+                line: 3,
+                column: 12,
+                found: 3,
+                expected: 2
+            }
+        );
+        assert_eq!(crate::parse_input("\n[#] (0) {1}\n\n").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn explain() {
+        let example_input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[#.] (1) {1,1}";
+        let explanation = crate::explain(example_input).unwrap();
+        let lines: Vec<&str> = explanation.lines().collect();
+        assert_eq!(
+            lines[0],
+            "line 1: [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+        );
+        assert_eq!(
+            lines[1],
+            "  lights: press (1,3) (2,3), 2 presses, replays to [.##.]: ok"
+        );
+        assert!(lines[2].starts_with("  joltages: press "));
+        assert!(lines[2].ends_with(", 10 presses, replays to {3,5,4,7}: ok"));
+        assert_eq!(lines[3], "line 2: [#.] (1) {1,1}");
+        assert_eq!(lines[4], "  lights: no presses reach the diagram");
+        assert_eq!(lines[5], "  joltages: no presses reach the joltages");
+    }
+}
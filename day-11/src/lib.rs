@@ -5,20 +5,18 @@ use std::collections::HashMap;
 type Connections = HashMap<String, Vec<String>>;
 type CountCache = HashMap<String, usize>;
 
-const INPUT: &str = include_str!("./input.txt");
-
-fn parse_input(input: &str) -> Connections {
+pub fn parse_input(input: &str) -> Connections {
     input
         .trim()
         .lines()
-        .filter_map(|line| {
+        .map(|line| {
             let (from, to) = line.split_once(": ").unwrap();
-            Some((
+            (
                 from.to_string(),
                 to.split_whitespace()
                     .map(std::string::ToString::to_string)
                     .collect(),
-            ))
+            )
         })
         .collect()
 }
@@ -49,16 +47,22 @@ fn count_paths(from: &str, to: &str, connections: &Connections, cache: &mut Coun
     total_paths
 }
 
-fn solve_part_one(input: &str) -> usize {
-    let connections = parse_input(input);
+pub fn solve_part_one(input: &str) -> usize {
+    solve_part_one_parsed(&parse_input(input))
+}
+
+pub fn solve_part_one_parsed(connections: &Connections) -> usize {
     let start_node = "you";
     let end_node = "out";
     let mut cache = CountCache::new();
-    count_paths(start_node, end_node, &connections, &mut cache)
+    count_paths(start_node, end_node, connections, &mut cache)
 }
 
-fn solve_part_two(input: &str) -> usize {
-    let connections = parse_input(input);
+pub fn solve_part_two(input: &str) -> usize {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed(connections: &Connections) -> usize {
     let start_node = "svr";
     let dac_node = "dac";
     let fft_node = "fft";
@@ -66,30 +70,23 @@ fn solve_part_two(input: &str) -> usize {
 
     // option 1: 'svr' -> 'dac' -> 'fft' -> 'out'
     let mut cache = CountCache::new();
-    let svr_to_dac = count_paths(start_node, dac_node, &connections, &mut cache);
+    let svr_to_dac = count_paths(start_node, dac_node, connections, &mut cache);
     let mut cache = CountCache::new();
-    let dac_to_fft = count_paths(dac_node, fft_node, &connections, &mut cache);
+    let dac_to_fft = count_paths(dac_node, fft_node, connections, &mut cache);
     let mut cache = CountCache::new();
-    let fft_to_out = count_paths(fft_node, end_node, &connections, &mut cache);
+    let fft_to_out = count_paths(fft_node, end_node, connections, &mut cache);
 
     // option 2: 'svr' -> 'fft' -> 'dac' -> 'out'
     let mut cache = CountCache::new();
-    let svr_to_fft = count_paths(start_node, fft_node, &connections, &mut cache);
+    let svr_to_fft = count_paths(start_node, fft_node, connections, &mut cache);
     let mut cache = CountCache::new();
-    let fft_to_dac = count_paths(fft_node, dac_node, &connections, &mut cache);
+    let fft_to_dac = count_paths(fft_node, dac_node, connections, &mut cache);
     let mut cache = CountCache::new();
-    let dac_to_out = count_paths(dac_node, end_node, &connections, &mut cache);
+    let dac_to_out = count_paths(dac_node, end_node, connections, &mut cache);
 
     (svr_to_dac * dac_to_fft * fft_to_out) + (svr_to_fft * fft_to_dac * dac_to_out)
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
@@ -4,7 +4,8 @@ use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
 
-const INPUT: &str = include_str!("./input.txt");
+use aoc::grid::Grid;
+
 const SIZE: usize = 3;
 
 #[derive(Debug)]
@@ -24,7 +25,7 @@ enum GridCell {
 }
 
 #[derive(Debug, Clone)]
-struct Region {
+pub struct Region {
     width: usize,
     height: usize,
     wishlist: Vec<usize>,
@@ -51,11 +52,9 @@ impl Region {
         }
         // check overlap: if shape contains '#' and grid is not empty
         let shape = present.get_shape();
-        for (dr, row) in shape.iter().enumerate() {
-            for (dc, &cell) in row.iter().enumerate() {
-                if cell && self.grid[row_idx + dr][col_idx + dc] != GridCell::Empty {
-                    return false;
-                }
+        for (offset, &cell) in shape.iter() {
+            if cell && self.grid[row_idx + offset.y][col_idx + offset.x] != GridCell::Empty {
+                return false;
             }
         }
 
@@ -64,22 +63,18 @@ impl Region {
 
     fn place_mut(&mut self, present: &Present, row_idx: usize, col_idx: usize) {
         let shape = present.get_shape();
-        for (dr, row) in shape.iter().enumerate() {
-            for (dc, &cell) in row.iter().enumerate() {
-                if cell {
-                    self.grid[row_idx + dr][col_idx + dc] = GridCell::Covered(present.id);
-                }
+        for (offset, &cell) in shape.iter() {
+            if cell {
+                self.grid[row_idx + offset.y][col_idx + offset.x] = GridCell::Covered(present.id);
             }
         }
     }
 
     fn unplace_mut(&mut self, present: &Present, row_idx: usize, col_idx: usize) {
         let shape = present.get_shape();
-        for (dr, row) in shape.iter().enumerate() {
-            for (dc, &cell) in row.iter().enumerate() {
-                if cell {
-                    self.grid[row_idx + dr][col_idx + dc] = GridCell::Empty;
-                }
+        for (offset, &cell) in shape.iter() {
+            if cell {
+                self.grid[row_idx + offset.y][col_idx + offset.x] = GridCell::Empty;
             }
         }
     }
@@ -93,9 +88,9 @@ impl Region {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Present {
-    shape: [[bool; SIZE]; SIZE],
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Present {
+    shape: Grid<bool>,
     id: usize,
 }
 
@@ -145,54 +140,42 @@ impl TryFrom<&str> for Present {
             return Err(ParseError);
         }
 
-        Ok(Present::new(shape, id))
+        Ok(Present::new(Grid::new(shape.concat(), SIZE, SIZE), id))
     }
 }
 
 impl Present {
-    fn new(shape: [[bool; SIZE]; SIZE], id: usize) -> Self {
+    fn new(shape: Grid<bool>, id: usize) -> Self {
         Present { shape, id }
     }
 
-    fn get_shape(&self) -> &[[bool; SIZE]; SIZE] {
+    fn get_shape(&self) -> &Grid<bool> {
         &self.shape
     }
 
     // Returns shape rotated 90 degrees in clockwise direction.
     fn rotate_cw(&self) -> Self {
-        let s = self.shape;
-        let new_shape = [
-            [s[2][0], s[1][0], s[0][0]],
-            [s[2][1], s[1][1], s[0][1]],
-            [s[2][2], s[1][2], s[0][2]],
-        ];
-        Present::new(new_shape, self.id)
+        Present::new(self.shape.rotate_cw(), self.id)
     }
 
     // Returns shape flipped along vertical axis.
     fn flip_hor(&self) -> Self {
-        let s = self.shape;
-        let new_shape = [
-            [s[0][2], s[0][1], s[0][0]],
-            [s[1][2], s[1][1], s[1][0]],
-            [s[2][2], s[2][1], s[2][0]],
-        ];
-        Present::new(new_shape, self.id)
+        Present::new(self.shape.flip_horizontal(), self.id)
     }
 
     /// Returns a set of all unique orientations of a `Present`.
     pub fn all_orientations(&self) -> HashSet<Present> {
         let mut orientations = HashSet::new(); // HashSet handles uniqueness
         // generate 4 rotations of present
-        let mut present = *self;
+        let mut present = self.clone();
         for _ in 0..4 {
-            orientations.insert(present);
+            orientations.insert(present.clone());
             present = present.rotate_cw();
         }
         // generate 4 rotations of horizontally flipped present
         present = self.flip_hor();
         for _ in 0..4 {
-            orientations.insert(present);
+            orientations.insert(present.clone());
             present = present.rotate_cw();
         }
 
@@ -200,7 +183,7 @@ impl Present {
     }
 }
 
-fn parse_input(input: &str) -> (Vec<Present>, Vec<Region>) {
+pub fn parse_input(input: &str) -> (Vec<Present>, Vec<Region>) {
     let mut presents = vec![];
     let blocks: Vec<&str> = input.trim().split("\n\n").collect();
     for present_str in &blocks[..blocks.len() - 1] {
@@ -236,8 +219,7 @@ fn count_cells_needed(present_ids: &[usize], all_orientations: &[Vec<Present>])
                 .unwrap()
                 .get_shape()
                 .iter()
-                .flat_map(|row| row.iter())
-                .filter(|&&cell| cell)
+                .filter(|&(_, &cell)| cell)
                 .count()
         })
         .sum()
@@ -281,8 +263,11 @@ fn try_fit_presents(
     false
 }
 
-fn solve(input: &str) -> usize {
-    let (presents, regions) = parse_input(input);
+pub fn solve(input: &str) -> usize {
+    solve_parsed(&parse_input(input))
+}
+
+pub fn solve_parsed((presents, regions): &(Vec<Present>, Vec<Region>)) -> usize {
     let all_orientations: Vec<Vec<Present>> = presents
         .iter()
         .map(|p| p.all_orientations().into_iter().collect())
@@ -312,11 +297,6 @@ fn solve(input: &str) -> usize {
     count
 }
 
-fn main() {
-    let answer = solve(INPUT);
-    println!("Answer:\n{answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
@@ -2,9 +2,8 @@
 
 use std::ops::RangeInclusive;
 
-const INPUT: &str = include_str!("./input.txt");
-
-fn parse_input(input: &str) -> Vec<RangeInclusive<u64>> {
+// Ranges stay unmerged, so an ID in two overlapping ranges counts twice.
+pub fn parse_input(input: &str) -> Vec<RangeInclusive<u64>> {
     input
         .trim()
         .split(',')
@@ -38,31 +37,32 @@ fn is_repeated_sequence(id: u64) -> bool {
         })
 }
 
-fn solve_part_one(input: &str) -> u64 {
-    let ranges = parse_input(input);
+pub fn solve_part_one(input: &str) -> u64 {
+    solve_part_one_parsed(&parse_input(input))
+}
+
+pub fn solve_part_one_parsed(ranges: &[RangeInclusive<u64>]) -> u64 {
     ranges
-        .into_iter()
+        .iter()
+        .cloned()
         .flatten()
         .filter(|&id| is_doubled_sequence(id))
         .sum()
 }
 
-fn solve_part_two(input: &str) -> u64 {
-    let ranges = parse_input(input);
+pub fn solve_part_two(input: &str) -> u64 {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed(ranges: &[RangeInclusive<u64>]) -> u64 {
     ranges
-        .into_iter()
+        .iter()
+        .cloned()
         .flatten()
         .filter(|&id| is_repeated_sequence(id))
         .sum()
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
@@ -78,4 +78,11 @@ mod tests {
         let answer = crate::solve_part_two(example_input);
         assert_eq!(answer, 4174379265);
     }
+
+    #[test]
+    fn overlapping_and_malformed_ranges() {
+        let example_input = "11-22,22-33,x-44";
+        let answer = crate::solve_part_one(example_input);
+        assert_eq!(answer, 11 + 22 + 22 + 33);
+    }
 }
@@ -1,9 +1,7 @@
 // Advent of Code - Day 3: Lobby
 
-const INPUT: &str = include_str!("./input.txt");
-
 #[derive(Debug)]
-struct BatteryBank {
+pub struct BatteryBank {
     ratings: Vec<u8>,
 }
 
@@ -42,23 +40,24 @@ impl BatteryBank {
     }
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = BatteryBank> {
-    input.trim().lines().map(BatteryBank::new)
+pub fn parse_input(input: &str) -> Vec<BatteryBank> {
+    input.trim().lines().map(BatteryBank::new).collect()
+}
+
+pub fn solve_part_one(input: &str) -> u64 {
+    solve_part_one_parsed(&parse_input(input))
 }
 
-fn solve_part_one(input: &str) -> u64 {
-    parse_input(input).map(|bank| bank.max_joltage(2)).sum()
+pub fn solve_part_one_parsed(banks: &[BatteryBank]) -> u64 {
+    banks.iter().map(|bank| bank.max_joltage(2)).sum()
 }
 
-fn solve_part_two(input: &str) -> u64 {
-    parse_input(input).map(|bank| bank.max_joltage(12)).sum()
+pub fn solve_part_two(input: &str) -> u64 {
+    solve_part_two_parsed(&parse_input(input))
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
+pub fn solve_part_two_parsed(banks: &[BatteryBank]) -> u64 {
+    banks.iter().map(|bank| bank.max_joltage(12)).sum()
 }
 
 #[cfg(test)]
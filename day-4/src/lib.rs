@@ -0,0 +1,87 @@
+// Advent of Code - Day 4: Printing Department
+
+use aoc::grid::{Grid, ParseGridError, UnexpectedChar};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseGridError<UnexpectedChar>> {
+    Grid::try_parse(input, |c| match c {
+        '@' | '.' => Ok(c),
+        _ => Err(UnexpectedChar(c)),
+    })
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseGridError<UnexpectedChar>> {
+    Ok(solve_part_one_parsed(&parse_input(input)?))
+}
+
+pub fn solve_part_one_parsed(grid: &Grid<char>) -> usize {
+    let mut accessable_rolls = 0;
+    for coord in grid.coordinates() {
+        if grid.get(&coord) == Some('@')
+            && grid
+                .surrounding(coord)
+                .iter()
+                .filter_map(|sur| sur.map(|(_, char)| *char))
+                .filter(|c| *c == '@')
+                .count()
+                < 4
+        {
+            accessable_rolls += 1;
+        }
+    }
+    accessable_rolls
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseGridError<UnexpectedChar>> {
+    Ok(solve_part_two_parsed(&parse_input(input)?))
+}
+
+pub fn solve_part_two_parsed(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
+    // accessible rolls are removed every generation, until none are left, so there are at most
+    // as many generations as rolls
+    let max_generations = grid.num_rows * grid.num_columns;
+    let evolution = grid.evolve(max_generations, |&cell, neighbours| {
+        let neighbouring_rolls = neighbours.iter().flatten().filter(|&&&c| c == '@').count();
+        if cell == '@' && neighbouring_rolls < 4 {
+            'x'
+        } else {
+            cell
+        }
+    });
+    evolution.changes
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        let answer = crate::solve_part_one(example_input).unwrap();
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        let answer = crate::solve_part_two(example_input).unwrap();
+        assert_eq!(answer, 43);
+    }
+}
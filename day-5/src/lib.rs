@@ -0,0 +1,67 @@
+// Advent of Code - Day 5: Cafeteria
+
+use aoc::interval::IntervalSet;
+
+pub fn parse_input(input: &str) -> (IntervalSet<u64>, Vec<u64>) {
+    let (raw_ranges, ingredients) = input.trim().split_once("\n\n").unwrap();
+    (
+        raw_ranges.parse().unwrap(),
+        ingredients.lines().map(|l| l.parse().unwrap()).collect(),
+    )
+}
+
+pub fn solve_part_one(input: &str) -> usize {
+    solve_part_one_parsed(&parse_input(input))
+}
+
+pub fn solve_part_one_parsed((fresh_ranges, ingredients): &(IntervalSet<u64>, Vec<u64>)) -> usize {
+    ingredients
+        .iter()
+        .filter(|&ingredient| fresh_ranges.contains(ingredient))
+        .count()
+}
+
+pub fn solve_part_two(input: &str) -> usize {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed((fresh_ranges, _): &(IntervalSet<u64>, Vec<u64>)) -> usize {
+    fresh_ranges.covered_len().try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+        let answer = crate::solve_part_one(example_input);
+        assert_eq!(answer, 3);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+        let answer = crate::solve_part_two(example_input);
+        assert_eq!(answer, 14);
+    }
+}
@@ -1,23 +1,16 @@
 // Advent of Code - Day 6: Trash Compactor
 
-use std::iter::IntoIterator;
+use aoc::grid::Grid;
 
-const INPUT: &str = include_str!("./input.txt");
-
-fn transpose2d<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let len = v[0].len();
-    let mut iters: Vec<_> = v.into_iter().map(IntoIterator::into_iter).collect();
-    (0..len)
-        .map(|_| {
-            iters
-                .iter_mut()
-                .map(|n| n.next().unwrap())
-                .collect::<Vec<T>>()
-        })
+fn columns<T: Clone>(rows: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    let (num_rows, num_columns) = (rows.len(), rows[0].len());
+    let transposed = Grid::new(rows.concat(), num_rows, num_columns).transpose();
+    (0..transposed.num_rows)
+        .map(|y| transposed.row(y).to_vec())
         .collect()
 }
 
-fn solve_part_one(input: &str) -> usize {
+pub fn solve_part_one(input: &str) -> usize {
     let lines: Vec<&str> = input.trim().lines().collect();
     let (operator_line, problems) = lines.split_last().unwrap();
     let operators: Vec<char> = operator_line
@@ -32,7 +25,7 @@ fn solve_part_one(input: &str) -> usize {
                 .collect()
         })
         .collect();
-    numbers = transpose2d(numbers);
+    numbers = columns(numbers);
     numbers
         .into_iter()
         .zip(operators)
@@ -44,7 +37,7 @@ fn solve_part_one(input: &str) -> usize {
         .sum()
 }
 
-fn solve_part_two(input: &str) -> usize {
+pub fn solve_part_two(input: &str) -> usize {
     let lines: Vec<&str> = input.lines().collect();
     let (operator_line, problems) = lines.split_last().unwrap();
     let operators: Vec<char> = operator_line
@@ -52,7 +45,7 @@ fn solve_part_two(input: &str) -> usize {
         .flat_map(|s| s.chars())
         .collect();
     let mut chars: Vec<Vec<char>> = problems.iter().map(|line| line.chars().collect()).collect();
-    chars = transpose2d(chars);
+    chars = columns(chars);
     let numbers: Vec<Vec<usize>> = chars
         .into_iter()
         .map(|col| col.into_iter().filter(|&c| c != ' ').collect())
@@ -76,13 +69,6 @@ fn solve_part_two(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
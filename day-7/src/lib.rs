@@ -0,0 +1,115 @@
+// Advent of Code - Day 7: Laboratories
+
+use aoc::coord::Coordinate;
+use aoc::direction::Direction;
+use aoc::grid::{Grid, ParseGridError, UnexpectedChar};
+use aoc::search;
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseGridError<UnexpectedChar>> {
+    Grid::try_parse(input, |c| match c {
+        '.' | '^' | 'S' => Ok(c),
+        _ => Err(UnexpectedChar(c)),
+    })
+}
+
+/// Finds position(s) of a tachyon beam after taking a single step in time
+fn beam_step(grid: &Grid<char>, position: Coordinate<usize>) -> Vec<Coordinate<usize>> {
+    let mut next_positions = Vec::new();
+    if let Some(position_south) = grid.step(position, Direction::South, 1)
+        && let Some(next_space) = grid.get(&position_south) {
+            if next_space == '.' {
+                // Tachyon beams pass freely through empty space (.)
+                next_positions.push(position_south);
+            } else {
+                // Tachyon beam encounters a splitter (^), a new tachyon beam continues from the
+                //  immediate left and from the immediate right of the splitter
+                if let Some(sw_position) = grid.step(position, Direction::SouthWest, 1) {
+                    next_positions.push(sw_position);
+                }
+                if let Some(se_position) = grid.step(position, Direction::SouthEast, 1) {
+                    next_positions.push(se_position);
+                }
+            }
+        }
+
+    next_positions
+}
+
+pub fn solve_part_one(input: &str) -> Result<usize, ParseGridError<UnexpectedChar>> {
+    Ok(solve_part_one_parsed(&parse_input(input)?))
+}
+
+pub fn solve_part_one_parsed(grid: &Grid<char>) -> usize {
+    let starting_position = *grid.search(&'S').first().unwrap();
+    // count the splitters reached by the tachyon beam
+    search::reachable(starting_position, |&position| beam_step(grid, position))
+        .into_iter()
+        .filter(|&position| {
+            grid.step(position, Direction::South, 1)
+                .and_then(|pos| grid.get(&pos))
+                .is_some_and(|space| space == '^')
+        })
+        .count()
+}
+
+pub fn solve_part_two(input: &str) -> Result<usize, ParseGridError<UnexpectedChar>> {
+    Ok(solve_part_two_parsed(&parse_input(input)?))
+}
+
+pub fn solve_part_two_parsed(grid: &Grid<char>) -> usize {
+    let starting_position = *grid.search(&'S').first().unwrap();
+    // every timeline flows along with the beam, splitting in two at each splitter
+    let timeline_counts = grid.count_paths([starting_position], |position, _| {
+        beam_step(grid, position)
+    });
+
+    // sum number of timelines that reached bottom of grid
+    timeline_counts.row(grid.num_rows - 1).iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+        let answer = crate::solve_part_one(example_input).unwrap();
+        assert_eq!(answer, 21);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+        let answer = crate::solve_part_two(example_input).unwrap();
+        assert_eq!(answer, 40);
+    }
+}
@@ -0,0 +1,143 @@
+// Advent of Code - Day 8: Playground
+
+use aoc::coord3::Coordinate3;
+use aoc::dsu::UnionFind;
+
+/// Number of closest junction box pairs to connect for the puzzle input.
+pub const NUM_CONNECTIONS: usize = 1000;
+
+pub type JunctionBox = Coordinate3<isize>;
+
+pub fn parse_input(input: &str) -> Vec<JunctionBox> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+pub fn solve_part_one(input: &str, num_connections: usize) -> usize {
+    solve_part_one_parsed(&parse_input(input), num_connections)
+}
+
+pub fn solve_part_one_parsed(junction_boxes: &[JunctionBox], num_connections: usize) -> usize {
+    let mut union_find = UnionFind::new(junction_boxes.len());
+    let mut distance_pairs: Vec<(usize, usize, usize)> = junction_boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            junction_boxes
+                .iter()
+                .enumerate()
+                .skip(i + 1)
+                .map(move |(j, b)| {
+                    let dist_sq = a.squared_euclidean(*b);
+                    (i, j, dist_sq)
+                })
+        })
+        .collect();
+    distance_pairs.sort_by_key(|&(_, _, distance)| distance);
+
+    // Build Union-Find data structure
+    for &(i, j, _distance) in distance_pairs.iter().take(num_connections) {
+        union_find.union(i, j);
+    }
+    let mut circuit_sizes = union_find.sizes();
+    circuit_sizes.sort_by(|a, b| b.cmp(a));
+
+    circuit_sizes.iter().take(3).product()
+}
+
+pub fn solve_part_two(input: &str) -> isize {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed(junction_boxes: &[JunctionBox]) -> isize {
+    let mut union_find = UnionFind::new(junction_boxes.len());
+    let mut distance_pairs: Vec<(usize, usize, usize)> = junction_boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            junction_boxes
+                .iter()
+                .enumerate()
+                .skip(i + 1)
+                .map(move |(j, b)| {
+                    let dist_sq = a.squared_euclidean(*b);
+                    (i, j, dist_sq)
+                })
+        })
+        .collect();
+    distance_pairs.sort_by_key(|&(_, _, distance)| distance);
+
+    let mut last_connection_idxs: Option<(usize, usize)> = None;
+    // Build Union-Find data structure until all junction boxes are in a single circuit
+    for &(i, j, _distance) in &distance_pairs {
+        if union_find.union(i, j) {
+            last_connection_idxs = Some((i, j));
+            if union_find.num_components() == 1 {
+                break;
+            }
+        }
+    }
+    if let Some((idx_a, idx_b)) = last_connection_idxs {
+        junction_boxes[idx_a].x * junction_boxes[idx_b].x
+    } else {
+        panic!("Could not connect all junction boxes!");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1() {
+        let example_input = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+        let answer = crate::solve_part_one(example_input, 10);
+        assert_eq!(answer, 40);
+    }
+
+    #[test]
+    fn part2() {
+        let example_input = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+        let answer = crate::solve_part_two(example_input);
+        assert_eq!(answer, 25272);
+    }
+}
@@ -2,9 +2,7 @@
 
 use aoc::coord::Coordinate;
 
-const INPUT: &str = include_str!("./input.txt");
-
-fn parse_input(input: &str) -> Vec<Coordinate<isize>> {
+pub fn parse_input(input: &str) -> Vec<Coordinate<isize>> {
     input
         .trim()
         .lines()
@@ -59,14 +57,16 @@ fn is_valid_rectangle(
     true
 }
 
-fn solve_part_one(input: &str) -> isize {
-    let tiles = parse_input(input);
+pub fn solve_part_one(input: &str) -> usize {
+    solve_part_one_parsed(&parse_input(input))
+}
+
+pub fn solve_part_one_parsed(tiles: &[Coordinate<isize>]) -> usize {
     let mut max_area = 0;
     for (i, coord_a) in tiles.iter().enumerate() {
         for coord_b in tiles.iter().skip(i + 1) {
-            let dx = coord_a.x - coord_b.x;
-            let dy = coord_a.y - coord_b.y;
-            let area = (dx.abs() + 1) * (dy.abs() + 1);
+            let span = coord_a.abs_diff(*coord_b);
+            let area = (span.x + 1) * (span.y + 1);
             if area > max_area {
                 max_area = area;
             }
@@ -75,8 +75,11 @@ fn solve_part_one(input: &str) -> isize {
     max_area
 }
 
-fn solve_part_two(input: &str) -> isize {
-    let red_tiles = parse_input(input);
+pub fn solve_part_two(input: &str) -> usize {
+    solve_part_two_parsed(&parse_input(input))
+}
+
+pub fn solve_part_two_parsed(red_tiles: &[Coordinate<isize>]) -> usize {
     let n = red_tiles.len();
     // create edges of polygon loop defined by red tiles
     let mut edges = Vec::new();
@@ -87,9 +90,8 @@ fn solve_part_two(input: &str) -> isize {
     let mut max_area = 0;
     for (i, coord_a) in red_tiles.iter().enumerate() {
         for coord_b in red_tiles.iter().skip(i + 1) {
-            let dx = coord_a.x - coord_b.x;
-            let dy = coord_a.y - coord_b.y;
-            let area = (dx.abs() + 1) * (dy.abs() + 1);
+            let span = coord_a.abs_diff(*coord_b);
+            let area = (span.x + 1) * (span.y + 1);
             if area <= max_area {
                 // skip rectangles smaller than current best
                 continue;
@@ -102,13 +104,6 @@ fn solve_part_two(input: &str) -> isize {
     max_area
 }
 
-fn main() {
-    let part_one_answer = solve_part_one(INPUT);
-    println!("Part one:\n{part_one_answer}");
-    let part_two_answer = solve_part_two(INPUT);
-    println!("Part two:\n{part_two_answer}");
-}
-
 #[cfg(test)]
 mod tests {
     #[test]
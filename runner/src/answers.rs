@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Part;
+use crate::input::{INPUTS_DIR, workspace_root};
+
+/// Answers file used when `--answers` is not given, kept next to the puzzle inputs.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[must_use]
+pub fn default_path() -> PathBuf {
+    workspace_root().join(INPUTS_DIR).join(DEFAULT_ANSWERS_FILE)
+}
+
+/// Known answers to the real puzzle inputs, keyed by day and part.
+///
+/// Stored as a small subset of TOML, one table per day:
+///
+/// ```toml
+/// [day-4]
+/// part-one = "1424"
+/// part-two = "8727"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    /// Reads the answers file at `path`; a missing file holds no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => contents.parse().map_err(|source| AnswersError::Invalid {
+                path: path.to_path_buf(),
+                source,
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AnswersError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.entries.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Stores the answer, returning the one it replaced.
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) -> Option<String> {
+        self.entries.insert((day, part), answer)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# Answers to the puzzle inputs, checked by `aoc verify`")?;
+        let mut current_day = None;
+        for (&(day, part), answer) in &self.entries {
+            if current_day != Some(day) {
+                writeln!(f, "\n[day-{day}]")?;
+                current_day = Some(day);
+            }
+            writeln!(f, "part-{part} = \"{}\"", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[') {
+                let parsed = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.trim().strip_prefix("day-"))
+                    .and_then(|number| number.parse().ok());
+                day = Some(parsed.ok_or(ParseAnswersError::InvalidTable { line: line_number })?);
+                continue;
+            }
+            let day = day.ok_or(ParseAnswersError::OutsideTable { line: line_number })?;
+            let (part, answer) =
+                parse_entry(line).ok_or(ParseAnswersError::InvalidEntry { line: line_number })?;
+            if answers.insert(day, part, answer).is_some() {
+                return Err(ParseAnswersError::Duplicate {
+                    line: line_number,
+                    day,
+                    part,
+                });
+            }
+        }
+        Ok(answers)
+    }
+}
+
+/// Parses `part-one = "answer"`, also accepting bare numbers as the answer.
+fn parse_entry(line: &str) -> Option<(Part, String)> {
+    let (key, value) = line.split_once('=')?;
+    let part = match key.trim() {
+        "part-one" => Part::One,
+        "part-two" => Part::Two,
+        _ => return None,
+    };
+    let value = value.trim();
+    let answer = match value.strip_prefix('"') {
+        Some(quoted) => unescape(quoted.strip_suffix('"')?)?,
+        None if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') => {
+            value.to_string()
+        }
+        None => return None,
+    };
+    Some((part, answer))
+}
+
+/// Escapes `answer` for a TOML basic string, the inverse of [`unescape`].
+fn escape(answer: &str) -> String {
+    let mut escaped = String::with_capacity(answer.len());
+    for c in answer.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", u32::from(c))),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Resolves the escapes of a TOML basic string without its quotes, or `None` if it has an
+/// invalid escape or an unescaped quote.
+fn unescape(quoted: &str) -> Option<String> {
+    let mut answer = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => {
+                let escaped = match chars.next()? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    unicode @ ('u' | 'U') => {
+                        let digits = if unicode == 'u' { 4 } else { 8 };
+                        let hex: String = chars.by_ref().take(digits).collect();
+                        if hex.len() != digits {
+                            return None;
+                        }
+                        char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                    }
+                    _ => return None,
+                };
+                answer.push(escaped);
+            }
+            c => answer.push(c),
+        }
+    }
+    Some(answer)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAnswersError {
+    InvalidTable { line: usize },
+    OutsideTable { line: usize },
+    InvalidEntry { line: usize },
+    Duplicate { line: usize, day: u8, part: Part },
+}
+
+impl fmt::Display for ParseAnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseAnswersError::InvalidTable { line } => {
+                write!(f, "line {line}: expected a table like `[day-7]`")
+            }
+            ParseAnswersError::OutsideTable { line } => {
+                write!(f, "line {line}: answer outside of a `[day-N]` table")
+            }
+            ParseAnswersError::InvalidEntry { line } => {
+                write!(
+                    f,
+                    "line {line}: expected `part-one = \"answer\"` or `part-two = ...`"
+                )
+            }
+            ParseAnswersError::Duplicate { line, day, part } => {
+                write!(f, "line {line}: second answer for day {day} part {part}")
+            }
+        }
+    }
+}
+
+impl Error for ParseAnswersError {}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Invalid {
+        path: PathBuf,
+        source: ParseAnswersError,
+    },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Unreadable { path, source } => {
+                write!(f, "could not read answers {}: {source}", path.display())
+            }
+            AnswersError::Invalid { path, source } => {
+                write!(f, "invalid answers file {}, {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Unreadable { source, .. } => Some(source),
+            AnswersError::Invalid { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let answers: Answers = "# comment\n[day-4]\npart-one = \"1424\"\npart-two = 8727\n\n[day-12]\npart-one = \"ok\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(4, Part::One), Some("1424"));
+        assert_eq!(answers.get(4, Part::Two), Some("8727"));
+        assert_eq!(answers.get(12, Part::Two), None);
+        assert_eq!(answers.to_string().parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn test_escaped_answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(8, Part::One, "say \"hi\"\\n".to_string());
+        answers.insert(8, Part::Two, "#..#\n.##.\t\u{7}".to_string());
+        let written = answers.to_string();
+        assert!(written.contains(r#"part-one = "say \"hi\"\\n""#));
+        assert!(written.contains(r##"part-two = "#..#\n.##.\t\u0007""##));
+        assert_eq!(written.parse::<Answers>(), Ok(answers));
+
+        let answers: Answers = "[day-1]\npart-one = \"\\u00e9\\U0001F384\""
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("\u{e9}\u{1F384}"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |s: &str| s.parse::<Answers>().unwrap_err();
+        assert_eq!(
+            parse("[day-x]"),
+            ParseAnswersError::InvalidTable { line: 1 }
+        );
+        assert_eq!(
+            parse("part-one = \"1\""),
+            ParseAnswersError::OutsideTable { line: 1 }
+        );
+        assert_eq!(
+            parse("[day-1]\npart-three = 1"),
+            ParseAnswersError::InvalidEntry { line: 2 }
+        );
+        assert_eq!(
+            parse("[day-1]\npart-one = \"a\\qb\""),
+            ParseAnswersError::InvalidEntry { line: 2 }
+        );
+        assert_eq!(
+            parse("[day-1]\npart-one = \"a\"b\""),
+            ParseAnswersError::InvalidEntry { line: 2 }
+        );
+        assert_eq!(
+            parse("[day-1]\npart-one = 1\n[day-1]\npart-one = 2"),
+            ParseAnswersError::Duplicate {
+                line: 4,
+                day: 1,
+                part: Part::One
+            }
+        );
+    }
+}
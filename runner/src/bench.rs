@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::cli::Part;
+use crate::days::Day;
+
+/// Number of timed runs per step when `--runs` is not given.
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// Will panic if `samples` is empty.
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timing of a single step of a day: parsing, or solving one of the parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub step: String,
+    pub stats: Stats,
+}
+
+fn time_runs(
+    runs: usize,
+    mut step: impl FnMut() -> Result<(), Box<dyn Error>>,
+) -> Result<Vec<Duration>, Box<dyn Error>> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            step()?;
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Times each requested part of `day` over `runs` runs.
+///
+/// Days with a separate parse step get a `parse` row, and a `solve` row per part timed on input
+/// parsed ahead of the runs. Other days get a `total` row per part that includes parsing.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    let mut measurements = Vec::new();
+    let mut parse_samples = Vec::new();
+    for _ in 0..runs {
+        let start = Instant::now();
+        if !day.solution.parse(input) {
+            break;
+        }
+        parse_samples.push(start.elapsed());
+    }
+    if !parse_samples.is_empty() {
+        measurements.push(Measurement {
+            day: day.number,
+            step: "parse".to_string(),
+            stats: Stats::from_samples(parse_samples),
+        });
+    }
+    for &part in parts {
+        let (step, samples) = match day.solution.prepare(input, part) {
+            Some(solver) => {
+                let solver = solver?;
+                ("solve", time_runs(runs, || solver().map(|_| ()))?)
+            }
+            None => {
+                let samples = time_runs(runs, || {
+                    let answer = match part {
+                        Part::One => day.solution.part_one(input),
+                        Part::Two => day.solution.part_two(input),
+                    };
+                    answer.map(|_| ())
+                })?;
+                ("total", samples)
+            }
+        };
+        measurements.push(Measurement {
+            day: day.number,
+            step: format!("part {part} {step}"),
+            stats: Stats::from_samples(samples),
+        });
+    }
+    Ok(measurements)
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", duration.as_secs_f64() * 1e6)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+/// Renders the measurements as a Markdown table, one row per step, in the given order.
+#[must_use]
+pub fn render_table(measurements: &[Measurement], runs: usize) -> String {
+    let mut table = format!(
+        "Timings over {runs} runs, part totals include parsing and part solves do not.\n\n"
+    );
+    table.push_str("| Day | Step           |        Min |     Median |        Max |\n");
+    table.push_str("|----:|:---------------|-----------:|-----------:|-----------:|\n");
+    for measurement in measurements {
+        let _ = writeln!(
+            table,
+            "| {:>3} | {:<14} | {:>10} | {:>10} | {:>10} |",
+            measurement.day,
+            measurement.step,
+            format_duration(measurement.stats.min),
+            format_duration(measurement.stats.median),
+            format_duration(measurement.stats.max),
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+        let stats = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(stats.median, ms(3));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_secs(23)), "23.00 s");
+    }
+}
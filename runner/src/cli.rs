@@ -0,0 +1,385 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use crate::answers;
+use crate::bench::DEFAULT_RUNS;
+use crate::input::InputSource;
+use crate::scaffold::NewDay;
+
+pub const USAGE: &str = "Usage: aoc <command>
+
+Commands:
+  run [DAYS] [OPTIONS]    Run the solvers for DAYS: a day (7), a range (3-7) or `all` (default)
+  bench [DAYS] [OPTIONS]  Time the parsing and solvers for DAYS
+  verify [DAYS] [OPTIONS] Check the answers for DAYS against the answers file
+  new <DAY> [OPTIONS]     Create the day-DAY crate and an empty inputs/day-DAY.txt
+  help                    Print this message
+
+Options:
+  -p, --part <1|2>        Only run one part of each day
+  -i, --input <PATH|->    Read the input of a single day from PATH, or from stdin for `-`,
+                          instead of inputs/day-N.txt
+  -n, --runs <N>          Number of timed runs per step for `bench` (default 10)
+  -o, --output <PATH>     Also write the `bench` results table to PATH
+  -a, --answers <PATH>    Answers file for `verify` (default inputs/answers.toml)
+      --explain           Also print how `run` arrives at each answer, for days that support it
+      --record            Save answers that `verify` has no record of yet
+  -t, --title <TITLE>     Puzzle title for the header of a `new` day
+      --no-aoc            Leave the shared `aoc` crate out of a `new` day's dependencies";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+/// Days, parts and input shared by the commands that run solvers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub days: DaySelection,
+    pub parts: Vec<Part>,
+    pub input: InputSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        explain: bool,
+    },
+    Bench {
+        selection: Selection,
+        runs: usize,
+        output: Option<PathBuf>,
+    },
+    Verify {
+        selection: Selection,
+        answers: PathBuf,
+        record: bool,
+    },
+    New(NewDay),
+    Help,
+}
+
+/// Parses the command line arguments, without the program name.
+pub fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some(command @ ("run" | "bench" | "verify")) => parse_solver_command(command, args),
+        Some("new") => parse_new(args),
+        None | Some("help" | "-h" | "--help") => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command `{other}`")),
+    }
+}
+
+fn parse_solver_command(
+    command: &str,
+    args: impl Iterator<Item = String>,
+) -> Result<Command, String> {
+    let mut days = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = InputSource::Conventional;
+    let mut runs = None;
+    let mut output = None;
+    let mut answers = None;
+    let mut record = false;
+    let mut explain = false;
+    // options that only apply to a single command, with that command
+    let mut specific = Vec::new();
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("`--part` needs a value")?;
+                parts = vec![parse_part(&part)?];
+            }
+            "--input" | "-i" => {
+                let path = args
+                    .next()
+                    .ok_or("`--input` needs a path, or `-` for stdin")?;
+                input = InputSource::from_arg(&path);
+            }
+            "--runs" | "-n" => {
+                let value = args.next().ok_or("`--runs` needs a value")?;
+                runs = Some(
+                    value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&runs| runs > 0)
+                        .ok_or(format!("runs must be a positive number, got `{value}`"))?,
+                );
+                specific.push(("--runs", "bench"));
+            }
+            "--output" | "-o" => {
+                let path = args.next().ok_or("`--output` needs a path")?;
+                output = Some(PathBuf::from(path));
+                specific.push(("--output", "bench"));
+            }
+            "--answers" | "-a" => {
+                let path = args.next().ok_or("`--answers` needs a path")?;
+                answers = Some(PathBuf::from(path));
+                specific.push(("--answers", "verify"));
+            }
+            "--explain" => {
+                explain = true;
+                specific.push(("--explain", "run"));
+            }
+            "--record" => {
+                record = true;
+                specific.push(("--record", "verify"));
+            }
+            _ if days.is_none() => days = Some(parse_days(&arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let days = days.unwrap_or(DaySelection::All);
+    let single_day = matches!(&days, DaySelection::Range(range) if range.start() == range.end());
+    if input != InputSource::Conventional && !single_day {
+        return Err("`--input` can only be used when running a single day".to_string());
+    }
+    if let Some((option, owner)) = specific.into_iter().find(|&(_, owner)| owner != command) {
+        return Err(format!(
+            "`{option}` only applies to `{owner}`, not `{command}`"
+        ));
+    }
+    let selection = Selection { days, parts, input };
+    Ok(match command {
+        "bench" => Command::Bench {
+            selection,
+            runs: runs.unwrap_or(DEFAULT_RUNS),
+            output,
+        },
+        "verify" => Command::Verify {
+            selection,
+            answers: answers.unwrap_or_else(answers::default_path),
+            record,
+        },
+        _ => Command::Run { selection, explain },
+    })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = args.next().ok_or("`new` needs the number of the day")?;
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|&day| day > 0)
+        .ok_or(format!("invalid day `{day}`, expected a positive number"))?;
+    let mut new_day = NewDay {
+        day,
+        title: None,
+        aoc: true,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" | "-t" => {
+                new_day.title = Some(args.next().ok_or("`--title` needs a value")?);
+            }
+            "--no-aoc" => new_day.aoc = false,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(Command::New(new_day))
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("part must be 1 or 2, got `{part}`")),
+    }
+}
+
+fn parse_days(days: &str) -> Result<DaySelection, String> {
+    if days == "all" {
+        return Ok(DaySelection::All);
+    }
+    let parse_day = |day: &str| {
+        day.parse::<u8>()
+            .map_err(|_| format!("invalid day `{day}`, expected a number, a range or `all`"))
+    };
+    match days.split_once('-') {
+        Some((first, last)) => Ok(DaySelection::Range(parse_day(first)?..=parse_day(last)?)),
+        None => {
+            let day = parse_day(days)?;
+            Ok(DaySelection::Range(day..=day))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn test_run_single_day_and_part() {
+        assert_eq!(
+            parse(&["run", "7", "--part", "2"]),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: DaySelection::Range(7..=7),
+                    parts: vec![Part::Two],
+                    input: InputSource::Conventional,
+                },
+                explain: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_range_and_all() {
+        assert_eq!(
+            parse(&["run", "3-5"]),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: DaySelection::Range(3..=5),
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                explain: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run"]),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: DaySelection::All,
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                explain: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_with_input() {
+        assert_eq!(
+            parse(&["run", "7", "--input", "-"]),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: DaySelection::Range(7..=7),
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Stdin,
+                },
+                explain: false,
+            })
+        );
+        assert!(parse(&["run", "all", "--input", "day-7.txt"]).is_err());
+        assert!(parse(&["run", "7", "--input"]).is_err());
+    }
+
+    #[test]
+    fn test_run_with_explain() {
+        assert_eq!(
+            parse(&["run", "10", "--explain"]),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: DaySelection::Range(10..=10),
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                explain: true,
+            })
+        );
+        assert!(parse(&["bench", "10", "--explain"]).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(
+            parse(&["bench", "10", "--runs", "3", "--output", "bench.md"]),
+            Ok(Command::Bench {
+                selection: Selection {
+                    days: DaySelection::Range(10..=10),
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                runs: 3,
+                output: Some(PathBuf::from("bench.md")),
+            })
+        );
+        assert!(parse(&["bench", "--runs", "0"]).is_err());
+        assert!(parse(&["run", "--runs", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            parse(&["verify", "--record"]),
+            Ok(Command::Verify {
+                selection: Selection {
+                    days: DaySelection::All,
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                answers: answers::default_path(),
+                record: true,
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "4", "-a", "answers.toml"]),
+            Ok(Command::Verify {
+                selection: Selection {
+                    days: DaySelection::Range(4..=4),
+                    parts: Part::BOTH.to_vec(),
+                    input: InputSource::Conventional,
+                },
+                answers: PathBuf::from("answers.toml"),
+                record: false,
+            })
+        );
+        assert!(parse(&["verify", "--runs", "3"]).is_err());
+        assert!(parse(&["bench", "--record"]).is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            parse(&["new", "13", "--title", "Some Puzzle", "--no-aoc"]),
+            Ok(Command::New(NewDay {
+                day: 13,
+                title: Some("Some Puzzle".to_string()),
+                aoc: false,
+            }))
+        );
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "0"]).is_err());
+        assert!(parse(&["new", "13", "--part", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(parse(&["run", "x"]).is_err());
+        assert!(parse(&["run", "7", "--part", "3"]).is_err());
+        assert!(parse(&["run", "7", "8"]).is_err());
+        assert!(parse(&["fly"]).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use std::error::Error;
+
+use crate::cli::Part;
+use crate::solution::{Answer, IntoAnswer, IntoParsed, PreparedSolver, Solution, SolveResult};
+
+/// Registered puzzle day.
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn Solution,
+}
+
+/// Implements [`Solution`] on a unit struct per day by forwarding to the day crate's solver
+/// functions, and lists all of them in [`DAYS`].
+///
+/// Days with a `parse` step also give the solvers that take its parsed output, as
+/// `part_one_parsed` and `part_two_parsed`.
+macro_rules! solutions {
+    ($(
+        $name:ident = $number:literal, $title:literal {
+            $(parse: $parse:expr,)?
+            part_one: $part_one:expr,
+            part_two: $part_two:expr
+            $(, part_one_parsed: $part_one_parsed:expr, part_two_parsed: $part_two_parsed:expr)?
+            $(, explain: $explain:expr)? $(,)?
+        }
+    )*) => {
+        $(
+            struct $name;
+
+            impl Solution for $name {
+                fn title(&self) -> &'static str {
+                    $title
+                }
+
+                fn part_one(&self, input: &str) -> SolveResult {
+                    let solve = $part_one;
+                    solve(input).into_answer()
+                }
+
+                fn part_two(&self, input: &str) -> SolveResult {
+                    let solve = $part_two;
+                    solve(input).into_answer()
+                }
+
+                $(
+                    fn parse(&self, input: &str) -> bool {
+                        let parse = $parse;
+                        let _ = std::hint::black_box(parse(input));
+                        true
+                    }
+
+                    fn prepare<'a>(
+                        &self,
+                        input: &'a str,
+                        part: Part,
+                    ) -> Option<Result<PreparedSolver<'a>, Box<dyn Error>>> {
+                        let parse = $parse;
+                        let parsed = match parse(input).into_parsed() {
+                            Ok(parsed) => parsed,
+                            Err(err) => return Some(Err(err)),
+                        };
+                        let solver: PreparedSolver<'a> = match part {
+                            Part::One => {
+                                Box::new(move || ($part_one_parsed)(&parsed).into_answer())
+                            }
+                            Part::Two => {
+                                Box::new(move || ($part_two_parsed)(&parsed).into_answer())
+                            }
+                        };
+                        Some(Ok(solver))
+                    }
+                )?
+
+                $(
+                    fn explain(&self, input: &str) -> Option<Result<String, Box<dyn Error>>> {
+                        let explain = $explain;
+                        Some(explain(input).map_err(Into::into))
+                    }
+                )?
+            }
+        )*
+
+        pub const DAYS: &[Day] = &[$(
+            Day {
+                number: $number,
+                solution: &$name,
+            },
+        )*];
+    };
+}
+
+solutions! {
+    Day1 = 1, "Secret Entrance" {
+        parse: day_1::parse_input,
+        part_one: day_1::solve_part_one,
+        part_two: day_1::solve_part_two,
+        part_one_parsed: day_1::solve_part_one_parsed,
+        part_two_parsed: day_1::solve_part_two_parsed,
+    }
+    Day2 = 2, "Gift Shop" {
+        parse: day_2::parse_input,
+        part_one: day_2::solve_part_one,
+        part_two: day_2::solve_part_two,
+        part_one_parsed: day_2::solve_part_one_parsed,
+        part_two_parsed: day_2::solve_part_two_parsed,
+    }
+    Day3 = 3, "Lobby" {
+        parse: day_3::parse_input,
+        part_one: day_3::solve_part_one,
+        part_two: day_3::solve_part_two,
+        part_one_parsed: day_3::solve_part_one_parsed,
+        part_two_parsed: day_3::solve_part_two_parsed,
+    }
+    Day4 = 4, "Printing Department" {
+        parse: day_4::parse_input,
+        part_one: day_4::solve_part_one,
+        part_two: day_4::solve_part_two,
+        part_one_parsed: day_4::solve_part_one_parsed,
+        part_two_parsed: day_4::solve_part_two_parsed,
+    }
+    Day5 = 5, "Cafeteria" {
+        parse: day_5::parse_input,
+        part_one: day_5::solve_part_one,
+        part_two: day_5::solve_part_two,
+        part_one_parsed: day_5::solve_part_one_parsed,
+        part_two_parsed: day_5::solve_part_two_parsed,
+    }
+    Day6 = 6, "Trash Compactor" {
+        part_one: day_6::solve_part_one,
+        part_two: day_6::solve_part_two,
+    }
+    Day7 = 7, "Laboratories" {
+        parse: day_7::parse_input,
+        part_one: day_7::solve_part_one,
+        part_two: day_7::solve_part_two,
+        part_one_parsed: day_7::solve_part_one_parsed,
+        part_two_parsed: day_7::solve_part_two_parsed,
+    }
+    Day8 = 8, "Playground" {
+        parse: day_8::parse_input,
+        part_one: |input| day_8::solve_part_one(input, day_8::NUM_CONNECTIONS),
+        part_two: day_8::solve_part_two,
+        part_one_parsed: |junction_boxes| {
+            day_8::solve_part_one_parsed(junction_boxes, day_8::NUM_CONNECTIONS)
+        },
+        part_two_parsed: day_8::solve_part_two_parsed,
+    }
+    Day9 = 9, "Movie Theater" {
+        parse: day_9::parse_input,
+        part_one: day_9::solve_part_one,
+        part_two: day_9::solve_part_two,
+        part_one_parsed: day_9::solve_part_one_parsed,
+        part_two_parsed: day_9::solve_part_two_parsed,
+    }
+    Day10 = 10, "Factory" {
+        parse: day_10::parse_input,
+        part_one: day_10::solve_part_one,
+        part_two: day_10::solve_part_two,
+        part_one_parsed: day_10::solve_part_one_parsed,
+        part_two_parsed: day_10::solve_part_two_parsed,
+        explain: day_10::explain,
+    }
+    Day11 = 11, "Reactor" {
+        parse: day_11::parse_input,
+        part_one: day_11::solve_part_one,
+        part_two: day_11::solve_part_two,
+        part_one_parsed: day_11::solve_part_one_parsed,
+        part_two_parsed: day_11::solve_part_two_parsed,
+    }
+    // the last day only has a single puzzle
+    Day12 = 12, "Christmas Tree Farm" {
+        parse: day_12::parse_input,
+        part_one: day_12::solve,
+        part_two: |_: &str| Answer::NotApplicable,
+        part_one_parsed: day_12::solve_parsed,
+        part_two_parsed: |_| Answer::NotApplicable,
+    }
+}
+
+#[must_use]
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
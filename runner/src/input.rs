@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::scaffold::find_workspace_root;
+
+/// Directory searched for `day-N.txt` when no input is given on the command line, inside the
+/// [`workspace_root`].
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/day-N.txt` in the [`workspace_root`].
+    Conventional,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a `--input` value, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Conventional => read_file(&conventional_path(&workspace_root(), day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The workspace containing the working directory, so every subcommand finds the same inputs
+/// from anywhere inside it. Outside a workspace this is the working directory itself.
+#[must_use]
+pub fn workspace_root() -> PathBuf {
+    std::env::current_dir()
+        .ok()
+        .and_then(|dir| find_workspace_root(&dir).ok())
+        .unwrap_or_default()
+}
+
+#[must_use]
+pub fn conventional_path(root: &Path, day: u8) -> PathBuf {
+    root.join(INPUTS_DIR).join(format!("day-{day}.txt"))
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(
+                f,
+                "no input file at {}, save the puzzle input there or pass `--input <PATH|->`",
+                path.display()
+            ),
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read input {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read input from stdin: {source}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file_names_expected_path() {
+        let err = InputSource::File(PathBuf::from("does/not/exist.txt"))
+            .load(7)
+            .unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+        let err = InputSource::Conventional.load(99).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                &conventional_path(&workspace_root(), 99)
+                    .display()
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_workspace_root() {
+        // tests run from the runner crate, one level below the workspace
+        let root = workspace_root();
+        assert!(root.join("runner").join("Cargo.toml").is_file());
+        assert!(conventional_path(&root, 4).ends_with("inputs/day-4.txt"));
+    }
+}
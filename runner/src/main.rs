@@ -0,0 +1,224 @@
+// Advent of Code 2025 - runs any day's solver by number
+
+mod answers;
+mod bench;
+mod cli;
+mod days;
+mod input;
+mod scaffold;
+mod solution;
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use answers::Answers;
+use cli::{Command, DaySelection, Part, Selection};
+use days::Day;
+use solution::Answer;
+
+fn select_days(selection: &DaySelection) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        DaySelection::All => Ok(days::DAYS.iter().collect()),
+        DaySelection::Range(range) if range.start() == range.end() => days::find(*range.start())
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("day {} has no solution", range.start())),
+        DaySelection::Range(range) => {
+            let selected: Vec<&Day> = days::DAYS
+                .iter()
+                .filter(|day| range.contains(&day.number))
+                .collect();
+            if selected.is_empty() {
+                Err(format!(
+                    "days {}-{} have no solutions",
+                    range.start(),
+                    range.end()
+                ))
+            } else {
+                Ok(selected)
+            }
+        }
+    }
+}
+
+/// Calls `f` with every selected day and its input, returning whether all calls succeeded.
+fn for_each_day(
+    selection: &Selection,
+    mut f: impl FnMut(&Day, &str) -> bool,
+) -> Result<bool, String> {
+    let mut success = true;
+    for day in select_days(&selection.days)? {
+        println!("Day {}: {}", day.number, day.solution.title());
+        match selection.input.load(day.number) {
+            Ok(input) => success &= f(day, &input),
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+            }
+        }
+    }
+    Ok(success)
+}
+
+/// Runs the requested parts of every selected day, returning whether all of them succeeded.
+fn run(selection: &Selection, explain: bool) -> Result<bool, String> {
+    for_each_day(selection, |day, input| {
+        let mut success = true;
+        for &part in &selection.parts {
+            let answer = match part {
+                Part::One => day.solution.part_one(input),
+                Part::Two => day.solution.part_two(input),
+            };
+            match answer {
+                Ok(answer) => println!("Part {part}:\n{answer}"),
+                Err(err) => {
+                    eprintln!("Part {part} failed: {err}");
+                    success = false;
+                }
+            }
+        }
+        if explain {
+            match day.solution.explain(input) {
+                Some(Ok(explanation)) => print!("Explanation:\n{explanation}"),
+                Some(Err(err)) => {
+                    eprintln!("Explanation failed: {err}");
+                    success = false;
+                }
+                None => println!("Explanation:\nn/a"),
+            }
+        }
+        success
+    })
+}
+
+/// Times every selected day and prints the results table, also writing it to `output`.
+fn bench(selection: &Selection, runs: usize, output: Option<&Path>) -> Result<bool, String> {
+    let mut measurements = Vec::new();
+    let success = for_each_day(selection, |day, input| {
+        match bench::bench_day(day, input, &selection.parts, runs) {
+            Ok(day_measurements) => {
+                measurements.extend(day_measurements);
+                true
+            }
+            Err(err) => {
+                eprintln!("Benchmark failed: {err}");
+                false
+            }
+        }
+    })?;
+    let table = bench::render_table(&measurements, runs);
+    println!("\n{table}");
+    if let Some(path) = output {
+        std::fs::write(path, &table)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    }
+    Ok(success)
+}
+
+/// Checks every selected part against the answers file, returning whether none of them
+/// changed or failed. With `record`, answers missing from the file are added to it.
+fn verify(selection: &Selection, path: &Path, record: bool) -> Result<bool, String> {
+    let mut answers = Answers::load(path).map_err(|err| err.to_string())?;
+    let (mut unchanged, mut changed, mut new) = (0, 0, 0);
+    let success = for_each_day(selection, |day, input| {
+        let mut success = true;
+        for &part in &selection.parts {
+            let answer = match part {
+                Part::One => day.solution.part_one(input),
+                Part::Two => day.solution.part_two(input),
+            };
+            let answer = match answer {
+                Ok(Answer::Value(answer)) => answer,
+                Ok(Answer::NotApplicable) => {
+                    println!("Part {part}: n/a");
+                    continue;
+                }
+                Err(err) => {
+                    eprintln!("Part {part} failed: {err}");
+                    success = false;
+                    continue;
+                }
+            };
+            match answers.get(day.number, part) {
+                Some(expected) if expected == answer => {
+                    println!("Part {part}: ok");
+                    unchanged += 1;
+                }
+                Some(expected) => {
+                    println!("Part {part}: CHANGED, expected {expected}, got {answer}");
+                    changed += 1;
+                    success = false;
+                }
+                None => {
+                    println!("Part {part}: new answer {answer}");
+                    new += 1;
+                    if record {
+                        answers.insert(day.number, part, answer);
+                    }
+                }
+            }
+        }
+        success
+    })?;
+    println!("\n{unchanged} unchanged, {changed} changed, {new} new");
+    if record && new > 0 {
+        answers
+            .save(path)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        println!("Recorded {new} new answers in {}", path.display());
+    }
+    Ok(success)
+}
+
+/// Creates the crate of a new day and tells how to hook it up to the runner.
+fn new_day(new_day: &scaffold::NewDay) -> Result<bool, String> {
+    let cwd = std::env::current_dir()
+        .map_err(|err| format!("could not read the working directory: {err}"))?;
+    let root = scaffold::find_workspace_root(&cwd).map_err(|err| err.to_string())?;
+    let created = scaffold::create_day(&root, new_day).map_err(|err| err.to_string())?;
+    println!("Created {}", created.crate_dir.display());
+    if let Some(input) = created.input {
+        println!("Created {}, paste the puzzle input there", input.display());
+    }
+    let day = new_day.day;
+    println!(
+        "\nTo run it with `aoc`, add `day-{day} = {{ path = \"../day-{day}\" }}` to runner/Cargo.toml \
+         and a `Day{day}` entry to the `solutions!` list in runner/src/days.rs."
+    );
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(true)
+        }
+        Command::Run { selection, explain } => run(&selection, explain),
+        Command::Bench {
+            selection,
+            runs,
+            output,
+        } => bench(&selection, runs, output.as_deref()),
+        Command::Verify {
+            selection,
+            answers,
+            record,
+        } => verify(&selection, &answers, record),
+        Command::New(day) => new_day(&day),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input::{INPUTS_DIR, conventional_path};
+
+/// Options for a generated day crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub day: u8,
+    pub title: Option<String>,
+    /// Whether the crate depends on the shared `aoc` crate.
+    pub aoc: bool,
+}
+
+impl NewDay {
+    fn manifest(&self) -> String {
+        let mut manifest = format!(
+            "[package]\nname = \"day-{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n",
+            self.day
+        );
+        if self.aoc {
+            manifest.push_str("aoc = { path = \"../aoc\" }\n");
+        }
+        manifest
+    }
+
+    fn lib(&self) -> String {
+        let header = match &self.title {
+            Some(title) => format!("// Advent of Code - Day {}: {title}", self.day),
+            None => format!("// Advent of Code - Day {}", self.day),
+        };
+        format!("{header}\n\n{LIB_TEMPLATE}")
+    }
+}
+
+const LIB_TEMPLATE: &str = r#"pub fn parse_input(input: &str) -> Vec<&str> {
+    input.trim().lines().collect()
+}
+
+pub fn solve_part_one(input: &str) -> usize {
+    let lines = parse_input(input);
+    lines.len()
+}
+
+pub fn solve_part_two(input: &str) -> usize {
+    let lines = parse_input(input);
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore = "example from the puzzle description still missing"]
+    fn part1() {
+        let example_input = "";
+        let answer = crate::solve_part_one(example_input);
+        assert_eq!(answer, 0);
+    }
+
+    #[test]
+    #[ignore = "example from the puzzle description still missing"]
+    fn part2() {
+        let example_input = "";
+        let answer = crate::solve_part_two(example_input);
+        assert_eq!(answer, 0);
+    }
+}
+"#;
+
+/// Paths written by [`create_day`].
+#[derive(Debug)]
+pub struct Created {
+    pub crate_dir: PathBuf,
+    /// `None` when the input file already existed and was left alone.
+    pub input: Option<PathBuf>,
+}
+
+/// The nearest of `dir` and its parents whose `Cargo.toml` has a `[workspace]` table.
+pub fn find_workspace_root(dir: &Path) -> Result<PathBuf, ScaffoldError> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| workspace_table(&manifest).is_some())
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| ScaffoldError::NotWorkspaceRoot {
+            path: dir.to_path_buf(),
+        })
+}
+
+/// The lines of the `[workspace]` table in `manifest`, without comments, or `None` if it has
+/// no such table.
+fn workspace_table(manifest: &str) -> Option<String> {
+    let mut lines = manifest
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim());
+    lines.find(|&line| line == "[workspace]")?;
+    let table: Vec<&str> = lines.take_while(|line| !line.starts_with('[')).collect();
+    Some(table.join("\n"))
+}
+
+/// The strings in the array assigned to `key` in a TOML `table`, which may span several lines.
+fn string_array(table: &str, key: &str) -> Vec<String> {
+    let Some(array) = table.lines().position(|line| {
+        line.split_once('=')
+            .is_some_and(|(name, value)| name.trim() == key && value.trim().starts_with('['))
+    }) else {
+        return Vec::new();
+    };
+    let rest = table.lines().skip(array).collect::<Vec<_>>().join("\n");
+    let (_, values) = rest.split_once('[').unwrap_or_default();
+    let values = values.split(']').next().unwrap_or_default();
+    values
+        .split(',')
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Whether `name` matches the Cargo members glob `pattern`, where `*` matches any run of
+/// characters and `?` any single one.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut pattern_chars = pattern.chars();
+    match pattern_chars.next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = pattern_chars.as_str();
+            name.char_indices()
+                .map(|(split, _)| split)
+                .chain([name.len()])
+                .any(|split| glob_matches(rest, &name[split..]))
+        }
+        Some(expected) => {
+            let mut name_chars = name.chars();
+            name_chars
+                .next()
+                .is_some_and(|c| expected == '?' || expected == c)
+                && glob_matches(pattern_chars.as_str(), name_chars.as_str())
+        }
+    }
+}
+
+/// Generates the `day-N` crate below the workspace `root` and an empty `inputs/day-N.txt`
+/// unless there already is one.
+///
+/// The workspace `members` must already cover the new crate, e.g. with a `day-*` glob, since
+/// an unlisted crate would silently be left out of the build.
+pub fn create_day(root: &Path, new_day: &NewDay) -> Result<Created, ScaffoldError> {
+    let manifest = root.join("Cargo.toml");
+    let Some(workspace) = fs::read_to_string(&manifest)
+        .ok()
+        .and_then(|contents| workspace_table(&contents))
+    else {
+        return Err(ScaffoldError::NotWorkspaceRoot {
+            path: root.to_path_buf(),
+        });
+    };
+    let name = format!("day-{}", new_day.day);
+    let is_member = string_array(&workspace, "members")
+        .iter()
+        .any(|member| glob_matches(member, &name))
+        && !string_array(&workspace, "exclude").contains(&name);
+    if !is_member {
+        return Err(ScaffoldError::NotMember { manifest, name });
+    }
+
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(ScaffoldError::DayExists { path: crate_dir });
+    }
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| ScaffoldError::Io { path, source }
+    };
+    let src_dir = crate_dir.join("src");
+    fs::create_dir_all(&src_dir).map_err(io_error(&src_dir))?;
+    let crate_manifest = crate_dir.join("Cargo.toml");
+    fs::write(&crate_manifest, new_day.manifest()).map_err(io_error(&crate_manifest))?;
+    let lib = src_dir.join("lib.rs");
+    fs::write(&lib, new_day.lib()).map_err(io_error(&lib))?;
+
+    let inputs_dir = root.join(INPUTS_DIR);
+    fs::create_dir_all(&inputs_dir).map_err(io_error(&inputs_dir))?;
+    let input = conventional_path(root, new_day.day);
+    let input = match OpenOptions::new().write(true).create_new(true).open(&input) {
+        Ok(_) => Some(input),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => None,
+        Err(source) => {
+            return Err(ScaffoldError::Io {
+                path: input,
+                source,
+            });
+        }
+    };
+    Ok(Created { crate_dir, input })
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    NotWorkspaceRoot { path: PathBuf },
+    NotMember { manifest: PathBuf, name: String },
+    DayExists { path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::NotWorkspaceRoot { path } => write!(
+                f,
+                "no workspace Cargo.toml in {} or any of its parents",
+                path.display()
+            ),
+            ScaffoldError::NotMember { manifest, name } => write!(
+                f,
+                "the workspace members in {} do not include {name}, add it or a `day-*` glob",
+                manifest.display()
+            ),
+            ScaffoldError::DayExists { path } => {
+                write!(
+                    f,
+                    "{} already exists, refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let new_day = NewDay {
+            day: 13,
+            title: Some("Test Day".to_string()),
+            aoc: true,
+        };
+        assert!(matches!(
+            create_day(&root, &new_day),
+            Err(ScaffoldError::NotWorkspaceRoot { .. })
+        ));
+
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"day-*\"]\n",
+        )
+        .unwrap();
+        let created = create_day(&root, &new_day).unwrap();
+        let manifest = fs::read_to_string(created.crate_dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day-13\""));
+        assert!(manifest.contains("aoc = { path = \"../aoc\" }"));
+        let lib = fs::read_to_string(created.crate_dir.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("// Advent of Code - Day 13: Test Day\n"));
+        assert_eq!(fs::read_to_string(created.input.unwrap()).unwrap(), "");
+
+        assert!(matches!(
+            create_day(&root, &new_day),
+            Err(ScaffoldError::DayExists { .. })
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_checks_members() {
+        let root = std::env::temp_dir().join(format!("aoc-members-{}", std::process::id()));
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        let new_day = NewDay {
+            day: 14,
+            title: None,
+            aoc: false,
+        };
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"x\"\n\n[workspace]\nmembers = [\n    \"aoc\", # shared\n    \"day-1\",\n]\n",
+        )
+        .unwrap();
+        assert_eq!(find_workspace_root(&root.join("runner/src")).unwrap(), root);
+        assert!(matches!(
+            create_day(&root, &new_day),
+            Err(ScaffoldError::NotMember { .. })
+        ));
+        assert!(!root.join("day-14").exists());
+
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"day-*\"]\nexclude = [\"day-14\"]\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            create_day(&root, &new_day),
+            Err(ScaffoldError::NotMember { .. })
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("day-*", "day-13"));
+        assert!(glob_matches("day-?", "day-7"));
+        assert!(glob_matches("*-1*", "day-13"));
+        assert!(!glob_matches("day-?", "day-13"));
+        assert!(!glob_matches("aoc", "day-13"));
+        assert!(glob_matches("day-?", "day-é"));
+        assert!(!glob_matches("day-??", "day-é"));
+        assert!(glob_matches("*é*", "café-1"));
+    }
+
+    #[test]
+    fn test_manifest_without_aoc() {
+        let new_day = NewDay {
+            day: 1,
+            title: None,
+            aoc: false,
+        };
+        assert!(!new_day.manifest().contains("aoc"));
+        assert!(new_day.lib().starts_with("// Advent of Code - Day 1\n"));
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::cli::Part;
+
+pub type SolveResult = Result<Answer, Box<dyn Error>>;
+
+/// Solver for one part that runs on input parsed ahead of time.
+pub type PreparedSolver<'a> = Box<dyn Fn() -> SolveResult + 'a>;
+
+/// Puzzle answer as printed by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Value(String),
+    /// The puzzle has no such part, e.g. the second part of the last day.
+    NotApplicable,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Value(value) => write!(f, "{value}"),
+            Answer::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+/// Common interface the runner uses to dispatch to each day's solver.
+pub trait Solution {
+    fn title(&self) -> &'static str;
+    fn part_one(&self, input: &str) -> SolveResult;
+    fn part_two(&self, input: &str) -> SolveResult;
+
+    /// Runs only the day's `parse_input` and drops the result, so benchmarks can time parsing
+    /// apart from solving. Returns `false` for days without a separate parse step.
+    fn parse(&self, _input: &str) -> bool {
+        false
+    }
+
+    /// Parses `input` once and returns a solver for `part` that runs on the parsed input, so
+    /// benchmarks can time solving apart from parsing. Returns `None` for days whose solvers
+    /// only take the raw input.
+    fn prepare<'a>(
+        &self,
+        _input: &'a str,
+        _part: Part,
+    ) -> Option<Result<PreparedSolver<'a>, Box<dyn Error>>> {
+        None
+    }
+
+    /// Describes how the day's solvers arrive at their answers, e.g. the steps they take for
+    /// each line of the input. Returns `None` for days without an explanation.
+    fn explain(&self, _input: &str) -> Option<Result<String, Box<dyn Error>>> {
+        None
+    }
+}
+
+/// Conversion from whatever a day's solver returns into a [`SolveResult`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> SolveResult;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> SolveResult {
+        Ok(self)
+    }
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> SolveResult {
+                    Ok(Answer::Value(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(u32, u64, usize, i32, i64, isize, String);
+
+impl<T, E> IntoAnswer for Result<T, E>
+where
+    T: IntoAnswer,
+    E: Error + 'static,
+{
+    fn into_answer(self) -> SolveResult {
+        self.map_err(|err| Box::new(err) as Box<dyn Error>)?
+            .into_answer()
+    }
+}
+
+/// Conversion from whatever a day's `parse_input` returns into the input its solvers take.
+pub trait IntoParsed {
+    type Parsed;
+    fn into_parsed(self) -> Result<Self::Parsed, Box<dyn Error>>;
+}
+
+impl<T> IntoParsed for Vec<T> {
+    type Parsed = Self;
+    fn into_parsed(self) -> Result<Self, Box<dyn Error>> {
+        Ok(self)
+    }
+}
+
+impl<A, B> IntoParsed for (A, B) {
+    type Parsed = Self;
+    fn into_parsed(self) -> Result<Self, Box<dyn Error>> {
+        Ok(self)
+    }
+}
+
+impl<K, V, S> IntoParsed for HashMap<K, V, S> {
+    type Parsed = Self;
+    fn into_parsed(self) -> Result<Self, Box<dyn Error>> {
+        Ok(self)
+    }
+}
+
+impl<T, E> IntoParsed for Result<T, E>
+where
+    E: Error + 'static,
+{
+    type Parsed = T;
+    fn into_parsed(self) -> Result<T, Box<dyn Error>> {
+        self.map_err(|err| Box::new(err) as Box<dyn Error>)
+    }
+}